@@ -2,7 +2,7 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use syn::{spanned::Spanned, Error, FnArg, Path, Type, TypePath};
 
-use crate::syn_ext;
+use crate::{derive_fn::CONSTRUCTOR_FN, syn_ext};
 
 pub fn derive_client(crate_path: &Path, ty: &str, name: &str, fns: &[syn_ext::Fn]) -> TokenStream {
     let ty_str = quote!(#ty).to_string();
@@ -10,6 +10,9 @@ pub fn derive_client(crate_path: &Path, ty: &str, name: &str, fns: &[syn_ext::Fn
     let mut errors = Vec::<Error>::new();
     let fns: Vec<_> = fns
         .iter()
+        // Constructors are invoked when contracts are registered or deployed,
+        // and cannot be invoked through the client.
+        .filter(|f| f.ident != CONSTRUCTOR_FN)
         .map(|f| {
             let fn_ident = &f.ident;
             let fn_try_ident = format_ident!("try_{}", &f.ident);
@@ -204,5 +207,11 @@ pub fn derive_client(crate_path: &Path, ty: &str, name: &str, fns: &[syn_ext::Fn
 
             #(#fns)*
         }
+
+        impl<'a> #crate_path::deploy::Client for #client_ident<'a> {
+            fn new(env: &#crate_path::Env, address: &#crate_path::Address) -> Self {
+                Self::new(env, address)
+            }
+        }
     }
 }
@@ -0,0 +1,272 @@
+use itertools::Itertools;
+use proc_macro2::{Literal, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{spanned::Spanned, DataStruct, DeriveInput, Error, Fields, Path};
+
+use stellar_xdr::{
+    ScSpecEntry, ScSpecTypeDef, ScSpecUdtStructFieldV0, ScSpecUdtStructV0, StringM, WriteXdr,
+    SCSYMBOL_LIMIT,
+};
+
+use crate::{doc::docs_from_attrs, map_type::map_type};
+
+// The maximum number of topics the host accepts for an event, of which the
+// first is the event name.
+const TOPICS_LIMIT: usize = 4;
+
+/// Derives an event from a struct with named fields.
+///
+/// Fields with the `topic` attribute are published as topics, following a
+/// topic that is the event name. The remaining fields are published as data, in
+/// a map keyed by the field names.
+///
+/// The spec of the event is a struct spec, as the spec has no entry for events.
+/// It has a field for each field of the struct, where each field's doc begins
+/// with `topic` or `data`.
+pub fn derive_event(
+    path: &Path,
+    input: &DeriveInput,
+    data: &DataStruct,
+    name: &Option<String>,
+    spec: bool,
+) -> TokenStream2 {
+    // Collect errors as they are encountered and emit them at the end.
+    let mut errors = Vec::<Error>::new();
+
+    let ident = &input.ident;
+    if !input.generics.params.is_empty() {
+        errors.push(Error::new(
+            input.generics.span(),
+            "generics are unsupported on contract events",
+        ));
+    }
+    let Fields::Named(fields) = &data.fields else {
+        return Error::new(
+            data.fields.span(),
+            "contract events must be structs with named fields",
+        )
+        .to_compile_error();
+    };
+
+    let name = name
+        .clone()
+        .unwrap_or_else(|| snake_case(&ident.to_string()));
+    if name.len() > SCSYMBOL_LIMIT as usize {
+        errors.push(Error::new(
+            ident.span(),
+            format!(
+                "event name is too long: {}, max is {}",
+                name.len(),
+                SCSYMBOL_LIMIT
+            ),
+        ));
+    }
+    if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        errors.push(Error::new(
+            ident.span(),
+            format!("event name {name} must contain only a-z, A-Z, 0-9, and _"),
+        ));
+    }
+
+    let is_topic = |field: &syn::Field| field.attrs.iter().any(|a| a.path().is_ident("topic"));
+    let (topic_fields, data_fields): (Vec<_>, Vec<_>) =
+        fields.named.iter().partition(|f| is_topic(f));
+    if topic_fields.len() > TOPICS_LIMIT - 1 {
+        errors.push(Error::new(
+            topic_fields[TOPICS_LIMIT - 1].span(),
+            format!(
+                "contract events have too many topic fields, max count {}",
+                TOPICS_LIMIT - 1
+            ),
+        ));
+    }
+
+    let spec_fields: Vec<_> = fields
+        .named
+        .iter()
+        .map(|field| {
+            let field_ident = field.ident.as_ref().unwrap();
+            let field_name = field_ident.to_string();
+            if field_name.len() > SCSYMBOL_LIMIT as usize {
+                errors.push(Error::new(
+                    field_ident.span(),
+                    format!(
+                        "event field name is too long: {}, max is {}",
+                        field_name.len(),
+                        SCSYMBOL_LIMIT
+                    ),
+                ));
+            }
+            let kind = if is_topic(field) { "topic" } else { "data" };
+            let docs = docs_from_attrs(&field.attrs);
+            let doc = if docs.is_empty() {
+                kind.to_string()
+            } else {
+                format!("{kind}: {docs}")
+            };
+            ScSpecUdtStructFieldV0 {
+                doc: doc.try_into().unwrap(), // TODO: Truncate docs, or display friendly compile error.
+                name: field_name.try_into().unwrap_or_else(|_| StringM::default()),
+                type_: match map_type(&field.ty) {
+                    Ok(t) => t,
+                    Err(e) => {
+                        errors.push(e);
+                        ScSpecTypeDef::I32
+                    }
+                },
+            }
+        })
+        .collect();
+
+    // If errors have occurred, render them instead.
+    if !errors.is_empty() {
+        let compile_errors = errors.iter().map(Error::to_compile_error);
+        return quote! { #(#compile_errors)* };
+    }
+
+    // Generated code spec.
+    let spec_gen = if spec {
+        let spec_entry = ScSpecEntry::UdtStructV0(ScSpecUdtStructV0 {
+            doc: docs_from_attrs(&input.attrs).try_into().unwrap(), // TODO: Truncate docs, or display friendly compile error.
+            lib: StringM::default(),
+            name: ident.to_string().try_into().unwrap(),
+            fields: spec_fields.try_into().unwrap(),
+        });
+        let spec_xdr = spec_entry.to_xdr().unwrap();
+        let spec_xdr_lit = proc_macro2::Literal::byte_string(spec_xdr.as_slice());
+        let spec_xdr_len = spec_xdr.len();
+        let spec_ident = format_ident!("__SPEC_XDR_EVENT_{}", ident.to_string().to_uppercase());
+        Some(quote! {
+            #[cfg_attr(target_family = "wasm", link_section = "contractspecv0")]
+            pub static #spec_ident: [u8; #spec_xdr_len] = #ident::spec_xdr();
+
+            impl #ident {
+                pub const fn spec_xdr() -> [u8; #spec_xdr_len] {
+                    *#spec_xdr_lit
+                }
+            }
+        })
+    } else {
+        None
+    };
+
+    let topic_idents: Vec<_> = topic_fields.iter().map(|f| &f.ident).collect();
+    let topic_count = topic_fields.len() as u32 + 1;
+    let (data_idents, data_names, data_idx_lits): (Vec<_>, Vec<_>, Vec<_>) = data_fields
+        .iter()
+        .map(|f| f.ident.as_ref().unwrap())
+        .sorted_by_key(|ident| ident.to_string())
+        .enumerate()
+        .map(|(i, ident)| (ident, ident.to_string(), Literal::usize_unsuffixed(i)))
+        .multiunzip();
+    let data_count = data_idents.len();
+
+    // Strip the topic attributes from the struct, as they are only meaningful
+    // to this macro.
+    let mut item = input.clone();
+    if let syn::Data::Struct(DataStruct {
+        fields: Fields::Named(fields),
+        ..
+    }) = &mut item.data
+    {
+        for field in fields.named.iter_mut() {
+            field.attrs.retain(|a| !a.path().is_ident("topic"));
+        }
+    }
+
+    // Output.
+    quote! {
+        #item
+
+        #spec_gen
+
+        impl #ident {
+            /// Publish the event from the currently executing contract.
+            pub fn publish(&self, env: &#path::Env) {
+                env.events().publish_event(self);
+            }
+        }
+
+        impl #path::events::Event for #ident {
+            fn topics(&self, env: &#path::Env) -> #path::Vec<#path::RawVal> {
+                use #path::IntoVal;
+                #path::vec![
+                    env,
+                    #path::Symbol::new(env, #name).into_val(env),
+                    #(self.#topic_idents.into_val(env),)*
+                ]
+            }
+
+            fn data(&self, env: &#path::Env) -> #path::RawVal {
+                use #path::{EnvBase, IntoVal, RawVal};
+                const KEYS: [&'static str; #data_count] = [#(#data_names),*];
+                let vals: [RawVal; #data_count] = [
+                    #(self.#data_idents.into_val(env)),*
+                ];
+                <_ as #path::unwrap::UnwrapOptimized>::unwrap_optimized(
+                    env.map_new_from_slices(&KEYS, &vals)
+                ).into()
+            }
+        }
+
+        #[cfg(any(test, feature = "testutils"))]
+        impl #path::testutils::ContractEvent for #ident {
+            fn try_from_event(
+                env: &#path::Env,
+                topics: &#path::Vec<#path::RawVal>,
+                data: &#path::RawVal,
+            ) -> Result<Self, #path::ConversionError> {
+                use #path::{ConversionError, EnvBase, MapObject, RawVal, Symbol, TryIntoVal};
+                if topics.len() != #topic_count {
+                    return Err(ConversionError);
+                }
+                let mut iter = topics.iter();
+                let name: Symbol = iter.next().ok_or(ConversionError)??.try_into_val(env).map_err(|_| ConversionError)?;
+                if name != Symbol::new(env, #name) {
+                    return Err(ConversionError);
+                }
+                #(
+                    let #topic_idents = iter.next().ok_or(ConversionError)??.try_into_val(env).map_err(|_| ConversionError)?;
+                )*
+                const KEYS: [&'static str; #data_count] = [#(#data_names),*];
+                let mut vals: [RawVal; #data_count] = [RawVal::VOID.to_raw(); #data_count];
+                let map: MapObject = data.try_into().map_err(|_| ConversionError)?;
+                env.map_unpack_to_slice(map, &KEYS, &mut vals).map_err(|_| ConversionError)?;
+                Ok(Self {
+                    #(#topic_idents,)*
+                    #(#data_idents: vals[#data_idx_lits].try_into_val(env).map_err(|_| ConversionError)?,)*
+                })
+            }
+        }
+    }
+}
+
+/// Converts an identifier in upper camel case to snake case.
+///
+/// A run of capitals is an acronym that is one word, except for its last
+/// capital if that begins the next word, so that `HTTPEvent` becomes
+/// `http_event`.
+fn snake_case(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let mut out = String::with_capacity(s.len());
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() {
+            let prev = i.checked_sub(1).map(|i| chars[i]);
+            let next = chars.get(i + 1);
+            let starts_word = match prev {
+                None | Some('_') => false,
+                Some(prev) if prev.is_ascii_uppercase() => {
+                    next.is_some_and(|next| next.is_ascii_lowercase())
+                }
+                Some(_) => true,
+            };
+            if starts_word {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
@@ -8,6 +8,11 @@ use syn::{
     Attribute, Error, FnArg, Ident, Pat, PatIdent, PatType, Path, Type, TypePath,
 };
 
+/// The name of the function that is a contract's constructor.
+///
+/// The SDK's `deploy::CONSTRUCTOR_FN` is defined from this name.
+pub const CONSTRUCTOR_FN: &str = "__constructor";
+
 #[allow(clippy::too_many_arguments)]
 pub fn derive_fn(
     crate_path: &Path,
@@ -91,12 +96,24 @@ pub fn derive_fn(
     } else {
         quote! {}
     };
-    let slice_args: Vec<TokenStream2> = (0..wrap_args.len()).map(|n| quote! { args[#n] }).collect();
     let use_trait = if let Some(t) = trait_ident {
         quote! { use super::#t }
     } else {
         quote! {}
     };
+    // Constructors are invoked with the salt the contract was deployed with
+    // preceding their arguments, and check that they are invoked by the
+    // contract that deployed them.
+    let (salt_arg, constructor_guard) = if ident == CONSTRUCTOR_FN {
+        (
+            Some(quote! { __salt: #crate_path::RawVal, }),
+            Some(quote! { #crate_path::deploy::__check_constructor(&env, &__salt); }),
+        )
+    } else {
+        (None, None)
+    };
+    let arg_count = wrap_args.len() + usize::from(salt_arg.is_some());
+    let slice_args: Vec<TokenStream2> = (0..arg_count).map(|n| quote! { args[#n] }).collect();
 
     // If errors have occurred, render them instead.
     if !errors.is_empty() {
@@ -113,8 +130,9 @@ pub fn derive_fn(
 
             #[deprecated(note = #deprecated_note)]
             #[cfg_attr(target_family = "wasm", export_name = #wrap_export_name)]
-            pub fn invoke_raw(env: #crate_path::Env, #(#wrap_args),*) -> #crate_path::RawVal {
+            pub fn invoke_raw(env: #crate_path::Env, #salt_arg #(#wrap_args),*) -> #crate_path::RawVal {
                 #use_trait;
+                #constructor_guard
                 <_ as #crate_path::IntoVal<#crate_path::Env, #crate_path::RawVal>>::into_val(
                     #[allow(deprecated)]
                     &#call(
@@ -1,15 +1,15 @@
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{format_ident, quote};
 use stellar_xdr::{
-    ScSpecEntry, ScSpecFunctionInputV0, ScSpecFunctionV0, ScSpecTypeDef, ScSymbol, StringM, VecM,
-    WriteXdr, SCSYMBOL_LIMIT,
+    ScMetaEntry, ScMetaV0, ScSpecEntry, ScSpecFunctionInputV0, ScSpecFunctionV0, ScSpecTypeDef,
+    ScSymbol, StringM, VecM, WriteXdr, SCSYMBOL_LIMIT,
 };
 use syn::{
     punctuated::Punctuated, spanned::Spanned, token::Comma, Attribute, Error, FnArg, Ident, Pat,
     ReturnType, Type, TypePath,
 };
 
-use crate::{doc::docs_from_attrs, map_type::map_type};
+use crate::{derive_fn::CONSTRUCTOR_FN, doc::docs_from_attrs, map_type::map_type};
 
 #[allow(clippy::too_many_arguments)]
 pub fn derive_fn_spec(
@@ -44,7 +44,8 @@ pub fn derive_fn_spec(
         FnArg::Receiver(_) => None,
     });
 
-    // Prepare the argument inputs.
+    // Prepare the argument inputs. The salt that constructors are invoked with
+    // is not an input, as it is passed by the deployer rather than by callers.
     let spec_args: Vec<_> = inputs
         .iter()
         .skip(if env_input.is_some() { 1 } else { 0 })
@@ -151,6 +152,26 @@ pub fn derive_fn_spec(
         None
     };
 
+    // The constructor is marked in the contract's meta, so that tools can find
+    // the constructor without relying on its name.
+    let constructor_meta = if ident == CONSTRUCTOR_FN && export {
+        let meta_entry = ScMetaEntry::ScMetaV0(ScMetaV0 {
+            key: "constructor".try_into().unwrap(),
+            val: name.try_into().unwrap(),
+        });
+        let meta_xdr = meta_entry.to_xdr().unwrap();
+        let meta_xdr_lit = proc_macro2::Literal::byte_string(meta_xdr.as_slice());
+        let meta_xdr_len = meta_xdr.len();
+        Some(quote! {
+            #[doc(hidden)]
+            #(#attrs)*
+            #[cfg_attr(target_family = "wasm", link_section = "contractmetav0")]
+            static __CONTRACT_META_CONSTRUCTOR: [u8; #meta_xdr_len] = *#meta_xdr_lit;
+        })
+    } else {
+        None
+    };
+
     // Generated code.
     Ok(quote! {
         #[doc(hidden)]
@@ -159,6 +180,8 @@ pub fn derive_fn_spec(
         #export_attr
         pub static #spec_ident: [u8; #spec_xdr_len] = #ty::#spec_fn_ident();
 
+        #constructor_meta
+
         impl #ty {
             #(#attrs)*
             pub const fn #spec_fn_ident() -> [u8; #spec_xdr_len] {
@@ -0,0 +1,126 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    spanned::Spanned, Attribute, DataEnum, Error, Fields, Ident, Index, Path, Type, Visibility,
+};
+
+use crate::derive_enum::derive_type_enum;
+
+/// Derives a typed storage key space from an enum.
+///
+/// Each variant becomes a key type in a module named after the enum, that can
+/// only be used to store values of the type declared by the variant's `value`
+/// attribute. Keys are converted to the same values that the enum would be
+/// converted to if it were a `contracttype`, so that data stored using an enum
+/// remains accessible using the key space.
+pub fn derive_storage_enum(
+    path: &Path,
+    vis: &Visibility,
+    enum_ident: &Ident,
+    attrs: &[Attribute],
+    data: &DataEnum,
+) -> TokenStream2 {
+    // Collect errors as they are encountered and emit them at the end.
+    let mut errors = Vec::<Error>::new();
+
+    let raw_ident = format_ident!("__Raw");
+    let (doc_attrs, other_attrs): (Vec<_>, Vec<_>) =
+        attrs.iter().partition(|a| a.path().is_ident("doc"));
+
+    // The raw enum is the contracttype that keys convert through. Attributes
+    // are stripped from its variants as the value attribute is only meaningful
+    // to this macro.
+    let mut raw_data = data.clone();
+    for variant in raw_data.variants.iter_mut() {
+        variant.attrs.clear();
+    }
+    let raw_variants = raw_data.variants.iter();
+
+    let keys = data
+        .variants
+        .iter()
+        .map(|variant| {
+            let case_ident = &variant.ident;
+            let value_attr = variant.attrs.iter().find(|a| a.path().is_ident("value"));
+            let value_ty = match value_attr.map(|a| a.parse_args::<Type>()) {
+                Some(Ok(t)) => t,
+                Some(Err(e)) => {
+                    errors.push(e);
+                    return quote! {};
+                }
+                None => {
+                    errors.push(Error::new(
+                        variant.span(),
+                        format!(
+                            "enum variant {} must declare the type of its value with #[value(Type)]",
+                            case_ident
+                        ),
+                    ));
+                    return quote! {};
+                }
+            };
+            let key_attrs = variant
+                .attrs
+                .iter()
+                .filter(|a| !a.path().is_ident("value"));
+            let (decl, construct) = match &variant.fields {
+                Fields::Unit => (quote! { ; }, quote! { #raw_ident::#case_ident }),
+                Fields::Unnamed(fields) => {
+                    let field_types = fields.unnamed.iter().map(|f| &f.ty);
+                    let field_idxs = (0..fields.unnamed.len()).map(Index::from);
+                    (
+                        quote! { ( #(pub #field_types),* ); },
+                        quote! { #raw_ident::#case_ident( #(self.#field_idxs.clone()),* ) },
+                    )
+                }
+                Fields::Named(_) => {
+                    errors.push(Error::new(
+                        variant.fields.span(),
+                        format!("enum variant {} has unsupported named fields", case_ident),
+                    ));
+                    return quote! {};
+                }
+            };
+            quote! {
+                #(#key_attrs)*
+                #(#other_attrs)*
+                pub struct #case_ident #decl
+
+                impl #path::storage::Key for #case_ident {
+                    #[inline(always)]
+                    fn to_key_val(&self, env: &#path::Env) -> #path::RawVal {
+                        <_ as #path::IntoVal<#path::Env, #path::RawVal>>::into_val(&#construct, env)
+                    }
+                }
+
+                impl #path::storage::KeyFor<#value_ty> for #case_ident {}
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // If errors have occurred, render them instead.
+    if !errors.is_empty() {
+        let compile_errors = errors.iter().map(Error::to_compile_error);
+        return quote! { #(#compile_errors)* };
+    }
+
+    let raw_derived = derive_type_enum(path, &raw_ident, &[], &raw_data, false, &None);
+
+    // Output.
+    quote! {
+        #(#doc_attrs)*
+        #[allow(non_snake_case)]
+        #vis mod #enum_ident {
+            use super::*;
+
+            #[doc(hidden)]
+            pub enum #raw_ident {
+                #(#raw_variants,)*
+            }
+
+            #raw_derived
+
+            #(#keys)*
+        }
+    }
+}
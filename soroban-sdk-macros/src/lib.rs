@@ -4,8 +4,10 @@ mod derive_client;
 mod derive_enum;
 mod derive_enum_int;
 mod derive_error_enum_int;
+mod derive_event;
 mod derive_fn;
 mod derive_spec_fn;
+mod derive_storage;
 mod derive_struct;
 mod derive_struct_tuple;
 mod doc;
@@ -17,8 +19,10 @@ use derive_client::derive_client;
 use derive_enum::derive_type_enum;
 use derive_enum_int::derive_type_enum_int;
 use derive_error_enum_int::derive_type_error_enum_int;
-use derive_fn::{derive_contract_function_set, derive_fn};
+use derive_event::derive_event;
+use derive_fn::{derive_contract_function_set, derive_fn, CONSTRUCTOR_FN};
 use derive_spec_fn::derive_fn_spec;
+use derive_storage::derive_storage_enum;
 use derive_struct::derive_type_struct;
 use derive_struct_tuple::derive_type_struct_tuple;
 
@@ -161,6 +165,15 @@ pub fn contractimpl(metadata: TokenStream, input: TokenStream) -> TokenStream {
     }
 }
 
+/// Expands to the name of the function that is a contract's constructor, so
+/// that the SDK and the code generated by [`contractimpl`] agree on it.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __constructor_fn(_input: TokenStream) -> TokenStream {
+    let name = Literal::string(CONSTRUCTOR_FN);
+    quote! { #name }.into()
+}
+
 #[derive(Debug, FromMeta)]
 struct MetadataArgs {
     key: String,
@@ -307,6 +320,101 @@ pub fn contracttype(metadata: TokenStream, input: TokenStream) -> TokenStream {
     .into()
 }
 
+#[derive(Debug, FromMeta)]
+struct ContractStorageArgs {
+    #[darling(default = "default_crate_path")]
+    crate_path: Path,
+}
+
+#[proc_macro_attribute]
+pub fn contractstorage(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    let args = match NestedMeta::parse_meta_list(metadata.into()) {
+        Ok(v) => v,
+        Err(e) => {
+            return TokenStream::from(darling::Error::from(e).write_errors());
+        }
+    };
+    let args = match ContractStorageArgs::from_list(&args) {
+        Ok(v) => v,
+        Err(e) => return e.write_errors().into(),
+    };
+    let input = parse_macro_input!(input as DeriveInput);
+    let derived = match &input.data {
+        Data::Enum(e) => {
+            if e.variants.iter().any(|v| v.discriminant.is_some()) {
+                Error::new(
+                    input.span(),
+                    "enums with integer values are unsupported as contract storage keys",
+                )
+                .to_compile_error()
+            } else {
+                derive_storage_enum(&args.crate_path, &input.vis, &input.ident, &input.attrs, e)
+            }
+        }
+        Data::Struct(s) => Error::new(
+            s.struct_token.span(),
+            "structs are unsupported as contract storage keys",
+        )
+        .to_compile_error(),
+        Data::Union(u) => Error::new(
+            u.union_token.span(),
+            "unions are unsupported as contract storage keys",
+        )
+        .to_compile_error(),
+    };
+    quote! {
+        #derived
+    }
+    .into()
+}
+
+#[derive(Debug, FromMeta)]
+struct ContractEventArgs {
+    #[darling(default = "default_crate_path")]
+    crate_path: Path,
+    name: Option<String>,
+    export: Option<bool>,
+}
+
+#[proc_macro_attribute]
+pub fn contractevent(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    let args = match NestedMeta::parse_meta_list(metadata.into()) {
+        Ok(v) => v,
+        Err(e) => {
+            return TokenStream::from(darling::Error::from(e).write_errors());
+        }
+    };
+    let args = match ContractEventArgs::from_list(&args) {
+        Ok(v) => v,
+        Err(e) => return e.write_errors().into(),
+    };
+    let input = parse_macro_input!(input as DeriveInput);
+    // If the export argument has a value, do as it instructs regarding
+    // exporting. If it does not have a value, export if the type is pub.
+    let gen_spec = if let Some(export) = args.export {
+        export
+    } else {
+        matches!(input.vis, Visibility::Public(_))
+    };
+    let derived = match &input.data {
+        Data::Struct(s) => derive_event(&args.crate_path, &input, s, &args.name, gen_spec),
+        Data::Enum(e) => Error::new(
+            e.enum_token.span(),
+            "enums are unsupported as contract events",
+        )
+        .to_compile_error(),
+        Data::Union(u) => Error::new(
+            u.union_token.span(),
+            "unions are unsupported as contract events",
+        )
+        .to_compile_error(),
+    };
+    quote! {
+        #derived
+    }
+    .into()
+}
+
 #[proc_macro_attribute]
 pub fn contracterror(metadata: TokenStream, input: TokenStream) -> TokenStream {
     let args = match NestedMeta::parse_meta_list(metadata.into()) {
@@ -36,32 +36,23 @@ pub struct Address {
     obj: AddressObject,
 }
 
+/// AddressKind is the kind of identity that an [`Address`] represents.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum AddressKind {
+    /// A Stellar account.
+    Account,
+    /// A contract.
+    Contract,
+}
+
 impl Debug for Address {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         #[cfg(target_family = "wasm")]
         write!(f, "Address(..)")?;
         #[cfg(not(target_family = "wasm"))]
-        {
-            use crate::env::internal::xdr;
-            use stellar_strkey::{ed25519, Contract, Strkey};
-            let sc_val = ScVal::try_from(self).map_err(|_| core::fmt::Error)?;
-            if let ScVal::Address(addr) = sc_val {
-                match addr {
-                    xdr::ScAddress::Account(account_id) => {
-                        let xdr::AccountId(xdr::PublicKey::PublicKeyTypeEd25519(xdr::Uint256(
-                            ed25519,
-                        ))) = account_id;
-                        let strkey = Strkey::PublicKeyEd25519(ed25519::PublicKey(ed25519));
-                        write!(f, "AccountId({})", strkey.to_string())?;
-                    }
-                    xdr::ScAddress::Contract(contract_id) => {
-                        let strkey = Strkey::Contract(Contract(contract_id.0));
-                        write!(f, "Contract({})", strkey.to_string())?;
-                    }
-                }
-            } else {
-                return Err(core::fmt::Error);
-            }
+        match self.kind() {
+            AddressKind::Account => write!(f, "AccountId({})", self.to_strkey())?,
+            AddressKind::Contract => write!(f, "Contract({})", self.to_strkey())?,
         }
         Ok(())
     }
@@ -247,10 +238,20 @@ impl Address {
         }
     }
 
+    /// Returns the kind of identity the `Address` represents, a Stellar
+    /// account or a contract.
+    pub fn kind(&self) -> AddressKind {
+        if self.try_contract_id().is_some() {
+            AddressKind::Contract
+        } else {
+            AddressKind::Account
+        }
+    }
+
     /// Returns 32-byte contract identifier corresponding to this `Address`.
     ///
     /// Returns None if the Address is not a contract.
-    pub(crate) fn try_contract_id(&self) -> Option<BytesN<32>> {
+    pub fn try_contract_id(&self) -> Option<BytesN<32>> {
         let rv = self.env.address_to_contract_id(self.obj).unwrap_optimized();
         if let Ok(()) = rv.try_into_val(&self.env) {
             None
@@ -271,6 +272,60 @@ impl Address {
         }
     }
 
+    /// Creates an `Address` from its strkey, a `G...` string for a Stellar
+    /// account, or a `C...` string for a contract.
+    ///
+    /// ### Panics
+    ///
+    /// If the strkey is not a valid account or contract strkey.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{Address, AddressKind, Env};
+    ///
+    /// # #[cfg(feature = "testutils")]
+    /// # fn main() {
+    /// let env = Env::default();
+    /// let strkey = "GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ";
+    /// let address = Address::from_strkey(&env, strkey);
+    /// assert_eq!(address.kind(), AddressKind::Account);
+    /// assert_eq!(address.to_strkey(), strkey);
+    /// # }
+    /// # #[cfg(not(feature = "testutils"))]
+    /// # fn main() { }
+    /// ```
+    #[cfg(not(target_family = "wasm"))]
+    pub fn from_strkey(env: &Env, strkey: &str) -> Self {
+        use crate::env::internal::xdr;
+        use stellar_strkey::{ed25519, Contract, Strkey};
+        let sc_addr = match Strkey::from_string(strkey) {
+            Ok(Strkey::PublicKeyEd25519(ed25519::PublicKey(key))) => ScAddress::Account(
+                xdr::AccountId(xdr::PublicKey::PublicKeyTypeEd25519(xdr::Uint256(key))),
+            ),
+            Ok(Strkey::Contract(Contract(contract_id))) => {
+                ScAddress::Contract(xdr::Hash(contract_id))
+            }
+            _ => panic!("invalid address strkey: {strkey}"),
+        };
+        Self::try_from_val(env, &sc_addr).unwrap()
+    }
+
+    /// Returns the strkey of the `Address`, a `G...` string for a Stellar
+    /// account, or a `C...` string for a contract.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn to_strkey(&self) -> std::string::String {
+        use crate::env::internal::xdr;
+        use stellar_strkey::{ed25519, Contract, Strkey};
+        let strkey = match ScAddress::try_from(self).unwrap() {
+            ScAddress::Account(xdr::AccountId(xdr::PublicKey::PublicKeyTypeEd25519(
+                xdr::Uint256(key),
+            ))) => Strkey::PublicKeyEd25519(ed25519::PublicKey(key)),
+            ScAddress::Contract(contract_id) => Strkey::Contract(Contract(contract_id.0)),
+        };
+        strkey.to_string()
+    }
+
     #[inline(always)]
     pub(crate) unsafe fn unchecked_new(env: Env, obj: AddressObject) -> Self {
         Self { env, obj }
@@ -318,3 +373,11 @@ impl crate::testutils::Address for Address {
         self.contract_id()
     }
 }
+
+#[cfg(any(test, feature = "testutils"))]
+#[cfg_attr(feature = "docs", doc(cfg(feature = "testutils")))]
+impl core::fmt::Display for Address {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.to_strkey())
+    }
+}
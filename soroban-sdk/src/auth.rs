@@ -1,5 +1,7 @@
 use crate::{contracttype, Address, BytesN, RawVal, Symbol, Vec};
 
+pub mod account;
+
 #[contracttype(crate_path = "crate", export = false)]
 #[derive(Clone)]
 pub struct Context {
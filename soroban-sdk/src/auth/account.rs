@@ -0,0 +1,283 @@
+//! Account contains building blocks for account contracts, that authorize
+//! invocations on behalf of their address by implementing `__check_auth`.
+//!
+//! [`Multisig`] is the configuration of an account with ed25519 signers, each
+//! with a weight. The account authorizes an invocation when the signers that
+//! signed the signature payload have a total weight that meets the threshold.
+//!
+//! A [`Policy`] decides the threshold required for each authorization context
+//! the account is asked to authorize, by inspecting the contract, function
+//! name, and args of the context. For example, a policy can require more
+//! signers for transfers above a limit, or deny authorizing calls to some
+//! contracts altogether.
+//!
+//! Signatures are passed to `__check_auth` as a [`Vec`] of [`Signature`]s,
+//! ordered by increasing public key.
+//!
+//! ### Examples
+//!
+//! ```
+//! use soroban_sdk::{
+//!     auth::{
+//!         account::{AccountError, Multisig, Signature},
+//!         Context,
+//!     },
+//!     contractimpl, BytesN, Env, Map, Symbol, Vec,
+//! };
+//!
+//! pub struct AccountContract;
+//!
+//! #[contractimpl]
+//! impl AccountContract {
+//!     pub fn init(env: Env, signers: Map<BytesN<32>, u32>, threshold: u32) {
+//!         let multisig = Multisig {
+//!             signers,
+//!             threshold,
+//!         };
+//!         env.storage().set(&Symbol::short("multisig"), &multisig);
+//!     }
+//!
+//!     #[allow(non_snake_case)]
+//!     pub fn __check_auth(
+//!         env: Env,
+//!         signature_payload: BytesN<32>,
+//!         signatures: Vec<Signature>,
+//!         auth_context: Vec<Context>,
+//!     ) -> Result<(), AccountError> {
+//!         let multisig: Multisig = env.storage().get(&Symbol::short("multisig")).unwrap();
+//!         // Require all signers to authorize calls to upgrade contracts.
+//!         let total_weight = multisig.total_weight();
+//!         let policy = |_: &Env, context: &Context, threshold: u32| {
+//!             if context.fn_name == Symbol::short("upgrade") {
+//!                 Some(total_weight)
+//!             } else {
+//!                 Some(threshold)
+//!             }
+//!         };
+//!         multisig.check_auth_with_policy(
+//!             &env,
+//!             &signature_payload,
+//!             &signatures,
+//!             &auth_context,
+//!             &policy,
+//!         )
+//!     }
+//! }
+//! #
+//! # fn main() { }
+//! ```
+use crate::{
+    auth::Context, contracterror, contracttype, unwrap::UnwrapOptimized, Bytes, BytesN, Env, Map,
+    Vec,
+};
+
+/// Errors returned when an account does not authorize an invocation.
+#[contracterror(crate_path = "crate", export = false)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AccountError {
+    /// A signature is by a public key that is not a signer of the account.
+    UnknownSigner = 1,
+    /// Signatures are not ordered by increasing public key, or a signer
+    /// signed more than once.
+    SignaturesNotOrdered = 2,
+    /// The total weight of the signers does not meet the required threshold.
+    InsufficientWeight = 3,
+    /// A policy denied an authorization context, or its threshold is zero.
+    ContextDenied = 4,
+}
+
+/// Signature is an ed25519 signature of the signature payload by a signer of
+/// an account.
+#[contracttype(crate_path = "crate", export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Signature {
+    pub public_key: BytesN<32>,
+    pub signature: BytesN<64>,
+}
+
+/// Multisig is the configuration of an account with weighted ed25519 signers.
+///
+/// Multisig is a contract type, so that account contracts can keep it in
+/// storage.
+#[contracttype(crate_path = "crate", export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Multisig {
+    /// The weight of each signer, by public key.
+    pub signers: Map<BytesN<32>, u32>,
+    /// The total weight of signers required to authorize a context, unless a
+    /// policy requires otherwise. A threshold of zero authorizes nothing.
+    pub threshold: u32,
+}
+
+impl Multisig {
+    /// Creates a configuration without signers and with the threshold.
+    ///
+    /// ### Panics
+    ///
+    /// If the threshold is zero, as it would not require any signers.
+    pub fn new(env: &Env, threshold: u32) -> Self {
+        if threshold == 0 {
+            panic!("multisig threshold must be greater than zero");
+        }
+        Self {
+            signers: Map::new(env),
+            threshold,
+        }
+    }
+
+    /// Sets the weight of the signer, adding the signer if it is not a signer
+    /// already.
+    pub fn set_signer(&mut self, public_key: BytesN<32>, weight: u32) {
+        self.signers.set(public_key, weight);
+    }
+
+    /// Removes the signer.
+    pub fn remove_signer(&mut self, public_key: BytesN<32>) {
+        self.signers.remove(public_key);
+    }
+
+    /// Returns the total weight of all signers.
+    pub fn total_weight(&self) -> u32 {
+        self.signers
+            .values()
+            .iter_unchecked()
+            .fold(0, u32::saturating_add)
+    }
+
+    /// Verifies the signatures of the signature payload, returning the total
+    /// weight of the signers.
+    ///
+    /// ### Errors
+    ///
+    /// If a signature is by a public key that is not a signer, or the
+    /// signatures are not ordered by increasing public key.
+    ///
+    /// ### Panics
+    ///
+    /// If a signature is invalid.
+    pub fn verify(
+        &self,
+        env: &Env,
+        signature_payload: &BytesN<32>,
+        signatures: &Vec<Signature>,
+    ) -> Result<u32, AccountError> {
+        let payload: Bytes = signature_payload.clone().into();
+        let mut weight: u32 = 0;
+        let mut prev_public_key: Option<BytesN<32>> = None;
+        for signature in signatures.iter_unchecked() {
+            if let Some(prev_public_key) = &prev_public_key {
+                if *prev_public_key >= signature.public_key {
+                    return Err(AccountError::SignaturesNotOrdered);
+                }
+            }
+            let signer_weight = self
+                .signers
+                .get(signature.public_key.clone())
+                .ok_or(AccountError::UnknownSigner)?
+                .unwrap_optimized();
+            env.crypto()
+                .ed25519_verify(&signature.public_key, &payload, &signature.signature);
+            weight = weight.saturating_add(signer_weight);
+            prev_public_key = Some(signature.public_key);
+        }
+        Ok(weight)
+    }
+
+    /// Checks that the signatures authorize every authorization context, with
+    /// the account's threshold required for each.
+    ///
+    /// Account contracts call this from `__check_auth` with its args.
+    ///
+    /// ### Errors
+    ///
+    /// If verifying the signatures fails as described in
+    /// [`verify`][Self::verify], or the total weight of the signers does not
+    /// meet the threshold.
+    ///
+    /// ### Panics
+    ///
+    /// If a signature is invalid.
+    pub fn check_auth(
+        &self,
+        env: &Env,
+        signature_payload: &BytesN<32>,
+        signatures: &Vec<Signature>,
+        auth_context: &Vec<Context>,
+    ) -> Result<(), AccountError> {
+        self.check_auth_with_policy(
+            env,
+            signature_payload,
+            signatures,
+            auth_context,
+            &DefaultPolicy,
+        )
+    }
+
+    /// Checks that the signatures authorize every authorization context, with
+    /// the threshold required for each decided by the policy.
+    ///
+    /// A threshold of zero denies the context, the same as a policy returning
+    /// `None`, so that no context is authorized without signers.
+    ///
+    /// ### Errors
+    ///
+    /// If verifying the signatures fails as described in
+    /// [`verify`][Self::verify], the policy denies a context, or the total
+    /// weight of the signers does not meet the threshold of a context.
+    ///
+    /// ### Panics
+    ///
+    /// If a signature is invalid.
+    pub fn check_auth_with_policy<P: Policy + ?Sized>(
+        &self,
+        env: &Env,
+        signature_payload: &BytesN<32>,
+        signatures: &Vec<Signature>,
+        auth_context: &Vec<Context>,
+        policy: &P,
+    ) -> Result<(), AccountError> {
+        let weight = self.verify(env, signature_payload, signatures)?;
+        for context in auth_context.iter_unchecked() {
+            let threshold = policy
+                .threshold(env, &context, self.threshold)
+                .filter(|&threshold| threshold > 0)
+                .ok_or(AccountError::ContextDenied)?;
+            if weight < threshold {
+                return Err(AccountError::InsufficientWeight);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Policy decides the threshold an account requires to authorize each
+/// authorization context.
+///
+/// Closures of the form `Fn(&Env, &Context, u32) -> Option<u32>` are
+/// policies.
+pub trait Policy {
+    /// Returns the total weight of signers required to authorize the
+    /// context, given the threshold of the account. Returns `None` or zero to
+    /// deny the context regardless of the signers.
+    fn threshold(&self, env: &Env, context: &Context, threshold: u32) -> Option<u32>;
+}
+
+/// DefaultPolicy requires the threshold of the account for every context.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultPolicy;
+
+impl Policy for DefaultPolicy {
+    fn threshold(&self, _env: &Env, _context: &Context, threshold: u32) -> Option<u32> {
+        Some(threshold)
+    }
+}
+
+impl<F> Policy for F
+where
+    F: Fn(&Env, &Context, u32) -> Option<u32>,
+{
+    fn threshold(&self, env: &Env, context: &Context, threshold: u32) -> Option<u32> {
+        self(env, context, threshold)
+    }
+}
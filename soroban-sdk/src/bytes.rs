@@ -13,7 +13,10 @@ use super::{
     ConversionError, Env, RawVal, TryFromVal, TryIntoVal,
 };
 
-use crate::unwrap::{UnwrapInfallible, UnwrapOptimized};
+use crate::{
+    iter::FromIteratorIn,
+    unwrap::{UnwrapInfallible, UnwrapOptimized},
+};
 #[cfg(doc)]
 use crate::{storage::Storage, Map, Vec};
 
@@ -567,6 +570,29 @@ impl IntoIterator for Bytes {
     }
 }
 
+impl FromIteratorIn<u8> for Bytes {
+    fn from_iter_in<I: IntoIterator<Item = u8>>(env: &Env, iter: I) -> Self {
+        // Bytes are buffered in guest memory, so that the bytes are copied to
+        // the host a chunk at a time rather than a byte at a time.
+        let mut bytes = Bytes::new(env);
+        let mut buf = [0u8; 64];
+        let mut iter = iter.into_iter();
+        loop {
+            let len = buf
+                .iter_mut()
+                .zip(&mut iter)
+                .map(|(slot, b)| *slot = b)
+                .count();
+            if len > 0 {
+                bytes.extend_from_slice(&buf[..len]);
+            }
+            if len < buf.len() {
+                return bytes;
+            }
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct BinIter(Bytes);
 
@@ -1233,4 +1259,24 @@ mod test {
         let arr_bin: BytesN<3> = bin.clone().try_into().unwrap();
         assert_eq!(format!("{:?}", arr_bin), "BytesN<3>(10, 20, 30)");
     }
+
+    #[test]
+    fn bytes_collect_in() {
+        use crate::iter::CollectIn;
+        let env = Env::default();
+
+        let bin: Bytes = core::iter::empty().collect_in(&env);
+        assert_eq!(bin, Bytes::new(&env));
+
+        // Longer than the chunks that are copied at a time.
+        for len in [1u8, 64, 65, 200] {
+            let bin: Bytes = (0..len).collect_in(&env);
+            let expected: std::vec::Vec<u8> = (0..len).collect();
+            assert_eq!(bin, Bytes::from_slice(&env, &expected));
+        }
+
+        let bin = Bytes::from_array(&env, &[1, 2, 3]);
+        let doubled: Bytes = bin.iter().map(|b| b * 2).collect_in(&env);
+        assert_eq!(doubled, Bytes::from_array(&env, &[2, 4, 6]));
+    }
 }
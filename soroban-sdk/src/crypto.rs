@@ -1,5 +1,7 @@
 //! Crypto contains functions for cryptographic functions.
-use crate::{env::internal, unwrap::UnwrapInfallible, Bytes, BytesN, Env};
+use crate::{env::internal, unwrap::UnwrapInfallible, Bytes, BytesN, Env, Vec};
+
+pub mod merkle;
 
 /// Crypto provides access to cryptographic functions.
 pub struct Crypto {
@@ -25,18 +27,44 @@ impl Crypto {
     /// Verifies an ed25519 signature.
     ///
     /// The signature is verified as a valid signature of the message by the
-    /// ed25519 public key.
+    /// ed25519 public key. Verification fails if the public key is malformed,
+    /// or if the signature is not a valid signature of the message.
+    ///
+    /// The host does not report the failure to the contract, and instead
+    /// aborts the contract invocation. Invokers of the contract observe the
+    /// failure as an error, such as from the `try_` functions of a contract
+    /// client.
+    ///
+    /// For that reason there is no fallible variant of this function that
+    /// returns a [`Result`]. The host function that verifies the signature
+    /// has no way to return a failed verification to the contract, so a
+    /// contract cannot handle a bad signature itself.
     ///
     /// ### Panics
     ///
     /// If the signature verification fails.
     pub fn ed25519_verify(&self, public_key: &BytesN<32>, message: &Bytes, signature: &BytesN<64>) {
         let env = self.env();
-        let _ = internal::Env::verify_sig_ed25519(
+        internal::Env::verify_sig_ed25519(
             env,
             public_key.to_object(),
             message.to_object(),
             signature.to_object(),
-        );
+        )
+        .unwrap_infallible();
+    }
+
+    /// Verifies many ed25519 signatures.
+    ///
+    /// Each entry is a public key, a message, and a signature, that is verified
+    /// as with [`ed25519_verify`][Crypto::ed25519_verify].
+    ///
+    /// ### Panics
+    ///
+    /// If the signature verification of any entry fails.
+    pub fn ed25519_verify_batch(&self, entries: &Vec<(BytesN<32>, Bytes, BytesN<64>)>) {
+        for (public_key, message, signature) in entries.iter_unchecked() {
+            self.ed25519_verify(&public_key, &message, &signature);
+        }
     }
 }
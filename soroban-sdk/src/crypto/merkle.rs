@@ -0,0 +1,201 @@
+//! Merkle contains functions for computing the roots of binary Merkle trees and
+//! verifying inclusion proofs.
+//!
+//! Trees are built from leaves that are 32 byte hashes, typically the hash of
+//! the data the leaf represents. Each node is the hash of the concatenation of
+//! its two children. When a level of the tree has an odd number of nodes, the
+//! last node has no sibling and is promoted to the next level unchanged. It is
+//! not paired with a copy of itself, which would give the trees of different
+//! leaves the same root, and would let a leaf be proven at more than one
+//! index.
+//!
+//! Two variants of trees are supported:
+//!
+//! - Positional trees, where the left child is always hashed first. Proofs are
+//!   verified using the index of the leaf and the number of leaves in the tree
+//!   to determine the position of each node. See [`root`] and [`verify`].
+//!
+//! - Sorted-pair trees, where the lesser child is always hashed first. Proofs
+//!   are verified without the index of the leaf. See [`root_sorted`] and
+//!   [`verify_sorted`].
+//!
+//! The hash function is configurable with the [`Hasher`] type parameter.
+//!
+//! Leaves and nodes are hashed without domain separation, so leaves should be
+//! hashes of data that cannot be confused with the concatenation of two
+//! hashes.
+//!
+//! ### Examples
+//!
+//! ```
+//! use soroban_sdk::{bytesn, crypto::merkle::{self, Sha256}, vec, Env};
+//!
+//! # #[cfg(feature = "testutils")]
+//! # fn main() {
+//! let env = Env::default();
+//! let leaves = vec![
+//!     &env,
+//!     bytesn!(&env, 0x0000000000000000000000000000000000000000000000000000000000000001),
+//!     bytesn!(&env, 0x0000000000000000000000000000000000000000000000000000000000000002),
+//!     bytesn!(&env, 0x0000000000000000000000000000000000000000000000000000000000000003),
+//! ];
+//! let root = merkle::root::<Sha256>(&env, &leaves);
+//!
+//! // The third leaf has no sibling, and its uncle is the node of the first two
+//! // leaves.
+//! let leaf = leaves.get_unchecked(2);
+//! let uncle = merkle::hash_pair::<Sha256>(
+//!     &env,
+//!     &leaves.get_unchecked(0),
+//!     &leaves.get_unchecked(1),
+//! );
+//! let proof = vec![&env, uncle];
+//! assert!(merkle::verify::<Sha256>(&env, &root, &leaf, 2, 3, &proof));
+//! assert!(!merkle::verify::<Sha256>(&env, &root, &leaf, 3, 3, &proof));
+//! # }
+//! # #[cfg(not(feature = "testutils"))]
+//! # fn main() { }
+//! ```
+use crate::{Bytes, BytesN, Env, Vec};
+
+/// Hasher is implemented by the hash functions that can be used to build Merkle
+/// trees.
+pub trait Hasher {
+    /// Returns the hash of the data.
+    fn hash(env: &Env, data: &Bytes) -> BytesN<32>;
+}
+
+/// The SHA-256 hash function, as computed by
+/// [`Crypto::sha256`][crate::crypto::Crypto::sha256].
+pub struct Sha256;
+
+impl Hasher for Sha256 {
+    fn hash(env: &Env, data: &Bytes) -> BytesN<32> {
+        env.crypto().sha256(data)
+    }
+}
+
+/// Returns the hash of the concatenation of the left and right nodes.
+pub fn hash_pair<H: Hasher>(env: &Env, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32> {
+    let mut data = Bytes::from(left);
+    data.append(&right.into());
+    H::hash(env, &data)
+}
+
+/// Returns the hash of the concatenation of the nodes, with the lesser node
+/// first.
+pub fn hash_sorted_pair<H: Hasher>(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    if a <= b {
+        hash_pair::<H>(env, a, b)
+    } else {
+        hash_pair::<H>(env, b, a)
+    }
+}
+
+/// Returns the root of the positional tree of the leaves.
+///
+/// ### Panics
+///
+/// If there are no leaves.
+pub fn root<H: Hasher>(env: &Env, leaves: &Vec<BytesN<32>>) -> BytesN<32> {
+    root_with(env, leaves, hash_pair::<H>)
+}
+
+/// Returns the root of the sorted-pair tree of the leaves.
+///
+/// ### Panics
+///
+/// If there are no leaves.
+pub fn root_sorted<H: Hasher>(env: &Env, leaves: &Vec<BytesN<32>>) -> BytesN<32> {
+    root_with(env, leaves, hash_sorted_pair::<H>)
+}
+
+fn root_with(
+    env: &Env,
+    leaves: &Vec<BytesN<32>>,
+    hash: fn(&Env, &BytesN<32>, &BytesN<32>) -> BytesN<32>,
+) -> BytesN<32> {
+    if leaves.is_empty() {
+        panic!("merkle tree has no leaves");
+    }
+    let mut level = leaves.clone();
+    while level.len() > 1 {
+        let mut next = Vec::new(env);
+        for i in (0..level.len()).step_by(2) {
+            let left = level.get_unchecked(i);
+            match level.get(i + 1) {
+                Some(right) => next.push_back(hash(env, &left, &right.unwrap())),
+                // The last node of a level with an odd number of nodes is
+                // promoted unchanged.
+                None => next.push_back(left),
+            }
+        }
+        level = next;
+    }
+    level.get_unchecked(0)
+}
+
+/// Verifies that the leaf is at the index in the positional tree of `count`
+/// leaves with the root.
+///
+/// The proof is the sibling of each node on the path from the leaf to the
+/// root, starting with the sibling of the leaf, and skipping the nodes that
+/// have no sibling because they are the last node of a level with an odd
+/// number of nodes.
+///
+/// The number of leaves is part of what is verified, and should come from the
+/// same trusted source as the root.
+///
+/// Returns true if the index is less than the number of leaves, and the root
+/// computed from the leaf and the whole proof is the root.
+pub fn verify<H: Hasher>(
+    env: &Env,
+    root: &BytesN<32>,
+    leaf: &BytesN<32>,
+    index: u32,
+    count: u32,
+    proof: &Vec<BytesN<32>>,
+) -> bool {
+    if index >= count {
+        return false;
+    }
+    let mut node = leaf.clone();
+    let (mut index, mut len) = (index, count);
+    let mut proof = proof.iter_unchecked();
+    while len > 1 {
+        // The last node of a level with an odd number of nodes has no sibling.
+        if index % 2 == 1 || index + 1 < len {
+            let Some(sibling) = proof.next() else {
+                return false;
+            };
+            node = if index % 2 == 0 {
+                hash_pair::<H>(env, &node, &sibling)
+            } else {
+                hash_pair::<H>(env, &sibling, &node)
+            };
+        }
+        index /= 2;
+        len = len / 2 + len % 2;
+    }
+    proof.next().is_none() && &node == root
+}
+
+/// Verifies that the leaf is in the sorted-pair tree with the root.
+///
+/// The proof is the sibling of each node on the path from the leaf to the
+/// root, starting with the sibling of the leaf, and skipping the nodes that
+/// have no sibling.
+///
+/// Returns true if the root computed from the leaf and proof is the root.
+pub fn verify_sorted<H: Hasher>(
+    env: &Env,
+    root: &BytesN<32>,
+    leaf: &BytesN<32>,
+    proof: &Vec<BytesN<32>>,
+) -> bool {
+    let mut node = leaf.clone();
+    for sibling in proof.iter_unchecked() {
+        node = hash_sorted_pair::<H>(env, &node, &sibling);
+    }
+    &node == root
+}
@@ -9,6 +9,9 @@
 //!
 //! The deployer can be created using [Env::deployer].
 //!
+//! The ID of a contract can be derived before it is deployed, using
+//! [DeployerWithCurrentContract::id] or [DeployerWithOtherContract::id].
+//!
 //! ### Examples
 //!
 //! ```
@@ -21,7 +24,9 @@
 //! #     pub fn f(env: Env, wasm_hash: BytesN<32>) {
 //! #         let salt = [0u8; 32];
 //! let deployer = env.deployer().with_current_contract(&salt);
+//! let predicted_id = deployer.id();
 //! let contract_id = deployer.deploy(&wasm_hash);
+//! assert_eq!(contract_id, predicted_id);
 //! #     }
 //! # }
 //! #
@@ -36,7 +41,100 @@
 //! # #[cfg(not(feature = "testutils"))]
 //! # fn main() { }
 //! ```
-use crate::{env::internal::Env as _, unwrap::UnwrapInfallible, Address, BytesN, Env, IntoVal};
+use crate::{
+    env::internal::Env as _,
+    unwrap::{UnwrapInfallible, UnwrapOptimized},
+    Address, Bytes, BytesN, Env, IntoVal, RawVal, Symbol, TryFromVal, Vec,
+};
+
+/// The name of the contract function that is the contract's constructor.
+///
+/// See [`contractimpl`][crate::contractimpl] for how constructors are defined.
+pub const CONSTRUCTOR_FN: &str = soroban_sdk_macros::__constructor_fn!();
+
+/// Returns the arguments that the constructor of a contract deployed with the
+/// salt is invoked with, which are the salt followed by `args`.
+pub(crate) fn constructor_invocation_args(
+    env: &Env,
+    salt: &BytesN<32>,
+    args: Vec<RawVal>,
+) -> Vec<RawVal> {
+    let mut constructor_args = Vec::from_array(env, [salt.to_raw()]);
+    constructor_args.append(&args);
+    constructor_args
+}
+
+/// The ID of the contract that the test environment registers to invoke the
+/// constructors of contracts registered with a constructor. The ID is the
+/// SHA-256 hash of `soroban-sdk constructor test deployer`, which is not a
+/// contract ID preimage, so no deployed contract has the ID.
+pub(crate) const TEST_DEPLOYER_ID: [u8; 32] = [
+    234, 75, 163, 84, 224, 87, 5, 94, 79, 69, 19, 16, 238, 191, 2, 86, 32, 43, 211, 104, 220, 14,
+    163, 142, 235, 220, 196, 225, 36, 107, 70, 222,
+];
+
+/// Panics unless the currently executing contract's constructor is invoked by
+/// the contract that deployed it, for the first time.
+///
+/// The invoking contract deployed the currently executing contract if the
+/// currently executing contract's ID is derived from the invoking contract's
+/// ID and the salt, or if the invoking contract is the one the test
+/// environment registers to invoke constructors. The first invocation is
+/// recorded in the contract's storage under a key reserved for the purpose,
+/// the constructor's name, so that the deployer cannot invoke the constructor
+/// again.
+///
+/// Called by constructors generated by [`contractimpl`][crate::contractimpl].
+#[doc(hidden)]
+pub fn __check_constructor(env: &Env, salt: &RawVal) {
+    let salt = BytesN::<32>::try_from_val(env, salt).unwrap_optimized();
+    let stack = env.call_stack();
+    let len = stack.len();
+    if len < 2 {
+        panic!("constructor must be invoked by the deployer");
+    }
+    let (deployer, _) = stack.get_unchecked(len - 2).unwrap_optimized();
+    let (contract_id, _) = stack.get_unchecked(len - 1).unwrap_optimized();
+    let deployer = deployer.contract_id();
+    if deployer != TEST_DEPLOYER_ID
+        && contract_id_from_contract(env, &deployer, &salt) != contract_id
+    {
+        panic!("constructor must be invoked by the deployer");
+    }
+
+    let storage = env.storage();
+    let key = Symbol::new(env, CONSTRUCTOR_FN);
+    if storage.has(&key) {
+        panic!("contract is already constructed");
+    }
+    storage.set(&key, &());
+}
+
+/// Client is implemented by the contract clients generated by
+/// [`contractimpl`][crate::contractimpl],
+/// [`contractclient`][crate::contractclient], and
+/// [`contractimport`][crate::contractimport].
+pub trait Client {
+    /// Create a client for the contract with the address.
+    fn new(env: &Env, address: &Address) -> Self;
+}
+
+/// The XDR discriminant of the `ENVELOPE_TYPE_CONTRACT_ID_FROM_CONTRACT`
+/// variant of `HashIdPreimage`, that contract IDs derived from a contract ID
+/// are hashed from.
+const ENVELOPE_TYPE_CONTRACT_ID_FROM_CONTRACT: i32 = 9;
+
+/// Derive the ID of a contract deployed by the contract with the given ID and
+/// salt, the same way the host does, as the SHA-256 hash of the XDR encoded
+/// `HashIdPreimage`.
+fn contract_id_from_contract(env: &Env, contract_id: &BytesN<32>, salt: &BytesN<32>) -> Address {
+    let mut preimage =
+        Bytes::from_array(env, &ENVELOPE_TYPE_CONTRACT_ID_FROM_CONTRACT.to_be_bytes());
+    preimage.append(&env.ledger().network_id().into());
+    preimage.append(&contract_id.clone().into());
+    preimage.append(&salt.clone().into());
+    Address::from_contract_id(&env.crypto().sha256(&preimage))
+}
 
 /// Deployer provides access to deploying contracts.
 pub struct Deployer {
@@ -65,9 +163,11 @@ impl Deployer {
         }
     }
 
-    #[doc(hidden)]
     /// Get a deployer for contracts that derive their contract IDs from the
     /// given contract ID and the provided salt.
+    ///
+    /// The deployer cannot deploy contracts, and is useful for deriving the
+    /// IDs of contracts deployed by other contracts.
     pub fn with_other_contract(
         &self,
         contract_id: &Address,
@@ -91,9 +191,16 @@ pub struct DeployerWithCurrentContract {
 
 impl DeployerWithCurrentContract {
     /// Return the ID of the contract defined by the deployer.
-    #[doc(hidden)]
+    ///
+    /// The ID is the ID that [deploy][DeployerWithCurrentContract::deploy]
+    /// returns, and may be used before the contract is deployed.
     pub fn id(&self) -> Address {
-        todo!()
+        let env = &self.env;
+        contract_id_from_contract(
+            env,
+            &env.current_contract_address().contract_id(),
+            &self.salt,
+        )
     }
 
     /// Deploy a contract.
@@ -113,9 +220,109 @@ impl DeployerWithCurrentContract {
         let id = unsafe { BytesN::<32>::unchecked_new(env.clone(), id) };
         Address::from_contract_id(&id)
     }
+
+    /// Deploy a contract and invoke its initialization function.
+    ///
+    /// The contract is deployed as with
+    /// [deploy][DeployerWithCurrentContract::deploy], and then the function
+    /// `init_fn` of the deployed contract is invoked with `args`. If the
+    /// invocation fails the currently executing contract fails, and the
+    /// deployment is discarded along with any other changes it made.
+    ///
+    /// Returns the deployed contract's ID.
+    pub fn deploy_with_init(
+        &self,
+        wasm_hash: &impl IntoVal<Env, BytesN<32>>,
+        init_fn: &Symbol,
+        args: Vec<RawVal>,
+    ) -> Address {
+        let id = self.deploy(wasm_hash);
+        self.env.invoke_contract::<RawVal>(&id, init_fn, args);
+        id
+    }
+
+    /// Deploy a contract and invoke its constructor.
+    ///
+    /// The contract is deployed as with
+    /// [deploy][DeployerWithCurrentContract::deploy], and then its constructor
+    /// is invoked with `constructor_args`, for example a tuple of the
+    /// constructor's arguments, preceded by the salt, which the constructor
+    /// checks to reject invocations by any contract other than its deployer.
+    /// If the constructor fails the currently executing contract fails, and the
+    /// deployment is discarded along with any other changes it made.
+    ///
+    /// See [`contractimpl`][crate::contractimpl] for how constructors are
+    /// defined.
+    ///
+    /// Returns the deployed contract's ID.
+    pub fn deploy_with_constructor(
+        &self,
+        wasm_hash: &impl IntoVal<Env, BytesN<32>>,
+        constructor_args: impl IntoVal<Env, Vec<RawVal>>,
+    ) -> Address {
+        let env = &self.env;
+        self.deploy_with_init(
+            wasm_hash,
+            &Symbol::new(env, CONSTRUCTOR_FN),
+            constructor_invocation_args(env, &self.salt, constructor_args.into_val(env)),
+        )
+    }
+
+    /// Deploy a contract and initialize it using a client of the contract.
+    ///
+    /// The contract is deployed as with
+    /// [deploy][DeployerWithCurrentContract::deploy], and then `init` is called
+    /// with a client of the deployed contract, such as the clients generated
+    /// by [`contractimpl`][crate::contractimpl],
+    /// [`contractclient`][crate::contractclient], and
+    /// [`contractimport`][crate::contractimport]. If an invocation made by
+    /// `init` fails the currently executing contract fails, and the deployment
+    /// is discarded along with any other changes it made.
+    ///
+    /// Returns the client of the deployed contract.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # use soroban_sdk::{contractimpl, Address, BytesN, Env};
+    /// #
+    /// # mod token {
+    /// #     use soroban_sdk::{contractimpl, Address, Env};
+    /// #     pub struct Token;
+    /// #     #[contractimpl]
+    /// #     impl Token {
+    /// #         pub fn initialize(env: Env, admin: Address) {}
+    /// #     }
+    /// # }
+    /// # use token::TokenClient;
+    /// #
+    /// # pub struct Contract;
+    /// #
+    /// # #[contractimpl]
+    /// # impl Contract {
+    /// #     pub fn f(env: Env, wasm_hash: BytesN<32>, admin: Address) {
+    /// #         let salt = [0u8; 32];
+    /// let deployer = env.deployer().with_current_contract(&salt);
+    /// let token = deployer.deploy_with_client(&wasm_hash, |token: &TokenClient| {
+    ///     token.initialize(&admin);
+    /// });
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() { }
+    /// ```
+    pub fn deploy_with_client<C, F>(&self, wasm_hash: &impl IntoVal<Env, BytesN<32>>, init: F) -> C
+    where
+        C: Client,
+        F: FnOnce(&C),
+    {
+        let id = self.deploy(wasm_hash);
+        let client = C::new(&self.env, &id);
+        init(&client);
+        client
+    }
 }
 
-#[doc(hidden)]
 /// A deployer for contracts that derive their contract IDs from the
 /// given contract ID and the provided salt.
 ///
@@ -129,9 +336,11 @@ pub struct DeployerWithOtherContract {
 }
 
 impl DeployerWithOtherContract {
-    #[doc(hidden)]
     /// Return the ID of the contract defined by the deployer.
+    ///
+    /// The ID is the ID of the contract that the contract the deployer was
+    /// created for deploys with the salt.
     pub fn id(&self) -> Address {
-        todo!()
+        contract_id_from_contract(&self.env, &self.contract_id.contract_id(), &self.salt)
     }
 }
@@ -140,6 +140,10 @@ pub struct MaybeEnv {
     maybe_env_impl: internal::MaybeEnvImpl,
     #[cfg(any(test, feature = "testutils"))]
     snapshot: Option<Rc<LedgerSnapshot>>,
+    #[cfg(any(test, feature = "testutils"))]
+    events_offset: Option<Rc<Cell<usize>>>,
+    #[cfg(any(test, feature = "testutils"))]
+    ledger_close_time: Option<Rc<Cell<Option<u64>>>>,
 }
 
 #[cfg(target_family = "wasm")]
@@ -151,6 +155,10 @@ impl TryFrom<MaybeEnv> for Env {
             env_impl: internal::EnvImpl {},
             #[cfg(any(test, feature = "testutils"))]
             snapshot: value.snapshot,
+            #[cfg(any(test, feature = "testutils"))]
+            events_offset: value.events_offset.unwrap_or_default(),
+            #[cfg(any(test, feature = "testutils"))]
+            ledger_close_time: value.ledger_close_time.unwrap_or_default(),
         })
     }
 }
@@ -169,6 +177,10 @@ impl MaybeEnv {
             maybe_env_impl: internal::EnvImpl {},
             #[cfg(any(test, feature = "testutils"))]
             snapshot: None,
+            #[cfg(any(test, feature = "testutils"))]
+            events_offset: None,
+            #[cfg(any(test, feature = "testutils"))]
+            ledger_close_time: None,
         }
     }
 }
@@ -181,6 +193,10 @@ impl MaybeEnv {
             maybe_env_impl: None,
             #[cfg(any(test, feature = "testutils"))]
             snapshot: None,
+            #[cfg(any(test, feature = "testutils"))]
+            events_offset: None,
+            #[cfg(any(test, feature = "testutils"))]
+            ledger_close_time: None,
         }
     }
 }
@@ -192,6 +208,10 @@ impl From<Env> for MaybeEnv {
             maybe_env_impl: value.env_impl,
             #[cfg(any(test, feature = "testutils"))]
             snapshot: value.snapshot,
+            #[cfg(any(test, feature = "testutils"))]
+            events_offset: Some(value.events_offset),
+            #[cfg(any(test, feature = "testutils"))]
+            ledger_close_time: Some(value.ledger_close_time),
         }
     }
 }
@@ -206,6 +226,10 @@ impl TryFrom<MaybeEnv> for Env {
                 env_impl,
                 #[cfg(any(test, feature = "testutils"))]
                 snapshot: value.snapshot,
+                #[cfg(any(test, feature = "testutils"))]
+                events_offset: value.events_offset.unwrap_or_default(),
+                #[cfg(any(test, feature = "testutils"))]
+                ledger_close_time: value.ledger_close_time.unwrap_or_default(),
             })
         } else {
             Err(ConversionError)
@@ -220,6 +244,10 @@ impl From<Env> for MaybeEnv {
             maybe_env_impl: Some(value.env_impl),
             #[cfg(any(test, feature = "testutils"))]
             snapshot: value.snapshot,
+            #[cfg(any(test, feature = "testutils"))]
+            events_offset: Some(value.events_offset),
+            #[cfg(any(test, feature = "testutils"))]
+            ledger_close_time: Some(value.ledger_close_time),
         }
     }
 }
@@ -237,6 +265,14 @@ pub struct Env {
     env_impl: internal::EnvImpl,
     #[cfg(any(test, feature = "testutils"))]
     snapshot: Option<Rc<LedgerSnapshot>>,
+    // The number of events recorded by the host that precede the events
+    // visible to testutils, because they have been cleared.
+    #[cfg(any(test, feature = "testutils"))]
+    pub(crate) events_offset: Rc<Cell<usize>>,
+    // The close time used when advancing ledgers in tests, if it has been
+    // set.
+    #[cfg(any(test, feature = "testutils"))]
+    pub(crate) ledger_close_time: Rc<Cell<Option<u64>>>,
 }
 
 impl Default for Env {
@@ -446,10 +482,12 @@ impl Env {
 use crate::auth;
 #[cfg(any(test, feature = "testutils"))]
 use crate::testutils::{
-    budget::Budget, random, Address as _, ContractFunctionSet, Ledger as _, MockAuth,
-    MockAuthContract,
+    budget::Budget, random, Address as _, AuthorizedInvocation, ContractFunctionSet, Ledger as _,
+    MockAuth, MockAuthContract, MockAuthInvoke,
 };
 #[cfg(any(test, feature = "testutils"))]
+use core::cell::Cell;
+#[cfg(any(test, feature = "testutils"))]
 use soroban_ledger_snapshot::LedgerSnapshot;
 #[cfg(any(test, feature = "testutils"))]
 use std::{path::Path, rc::Rc};
@@ -493,6 +531,8 @@ impl Env {
         let env = Env {
             env_impl,
             snapshot: None,
+            events_offset: Default::default(),
+            ledger_close_time: Default::default(),
         };
 
         env.ledger().set(internal::LedgerInfo {
@@ -576,6 +616,59 @@ impl Env {
         contract_id
     }
 
+    /// Register a contract with the [Env] for testing, and invoke its
+    /// constructor.
+    ///
+    /// The contract is registered as with
+    /// [`register_contract`][Env::register_contract], and then its constructor
+    /// is invoked with `constructor_args`, for example a tuple of the
+    /// constructor's arguments. The constructor is invoked by a contract that
+    /// the [Env] registers for the purpose, which constructors accept as their
+    /// deployer. See [`contractimpl`][crate::contractimpl] for how constructors
+    /// are defined.
+    ///
+    /// Returns the contract ID of the registered contract.
+    ///
+    /// ### Panics
+    ///
+    /// If the constructor fails.
+    ///
+    /// ### Examples
+    /// ```
+    /// use soroban_sdk::{contractimpl, Env, Symbol};
+    ///
+    /// pub struct HelloContract;
+    ///
+    /// #[contractimpl]
+    /// impl HelloContract {
+    ///     pub fn __constructor(env: Env, greeting: Symbol) {
+    ///         env.storage().set(&Symbol::short("greeting"), &greeting);
+    ///     }
+    /// }
+    ///
+    /// #[test]
+    /// fn test() {
+    /// # }
+    /// # fn main() {
+    ///     let env = Env::default();
+    ///     let contract_id = env.register_contract_with_constructor(
+    ///         None,
+    ///         HelloContract,
+    ///         (Symbol::short("hello"),),
+    ///     );
+    /// }
+    /// ```
+    pub fn register_contract_with_constructor<'a, T: ContractFunctionSet + 'static>(
+        &self,
+        contract_id: impl Into<Option<&'a Address>>,
+        contract: T,
+        constructor_args: impl IntoVal<Env, Vec<RawVal>>,
+    ) -> Address {
+        let contract_id = self.register_contract(contract_id, contract);
+        self.invoke_constructor(&contract_id, constructor_args.into_val(self));
+        contract_id
+    }
+
     /// Register a contract in a WASM file with the [Env] for testing.
     ///
     /// Passing a contract ID for the first arguments registers the contract
@@ -612,6 +705,62 @@ impl Env {
         )
     }
 
+    /// Register a contract in a WASM file with the [Env] for testing, and
+    /// invoke its constructor.
+    ///
+    /// The contract is registered as with
+    /// [`register_contract_wasm`][Env::register_contract_wasm], and then its
+    /// constructor is invoked as with
+    /// [`register_contract_with_constructor`][Env::register_contract_with_constructor].
+    ///
+    /// Returns the contract ID of the registered contract.
+    ///
+    /// ### Panics
+    ///
+    /// If the constructor fails.
+    pub fn register_contract_wasm_with_constructor<'a>(
+        &self,
+        contract_id: impl Into<Option<&'a Address>>,
+        contract_wasm: &[u8],
+        constructor_args: impl IntoVal<Env, Vec<RawVal>>,
+    ) -> Address {
+        let contract_id = self.register_contract_wasm(contract_id, contract_wasm);
+        self.invoke_constructor(&contract_id, constructor_args.into_val(self));
+        contract_id
+    }
+
+    /// Invokes the constructor of the contract from the contract registered
+    /// at the ID that constructors accept as their deployer in tests.
+    fn invoke_constructor(&self, contract_id: &Address, args: Vec<RawVal>) {
+        struct ConstructorDeployer;
+        impl ContractFunctionSet for ConstructorDeployer {
+            fn call(&self, _func: &str, env: Env, args: &[RawVal]) -> Option<RawVal> {
+                let [contract_id, args] = args else {
+                    return None;
+                };
+                let contract_id = Address::try_from_val(&env, contract_id).unwrap();
+                let args = Vec::<RawVal>::try_from_val(&env, args).unwrap();
+                Some(env.invoke_contract(
+                    &contract_id,
+                    &crate::Symbol::new(&env, crate::deploy::CONSTRUCTOR_FN),
+                    args,
+                ))
+            }
+        }
+
+        let deployer =
+            Address::from_contract_id(&BytesN::from_array(self, &crate::deploy::TEST_DEPLOYER_ID));
+        self.register_contract(&deployer, ConstructorDeployer);
+        // The salt is only checked for deployers other than the test deployer.
+        let salt = BytesN::from_array(self, &[0; 32]);
+        let args = crate::deploy::constructor_invocation_args(self, &salt, args);
+        self.invoke_contract::<RawVal>(
+            &deployer,
+            &crate::Symbol::short("deploy"),
+            (contract_id.clone(), args).into_val(self),
+        );
+    }
+
     /// Register the built-in Stellar Asset Contract with provided admin address.
     ///
     /// Returns the contract ID of the registered token contract.
@@ -625,41 +774,7 @@ impl Env {
             issuer_pk.clone(),
         )));
 
-        self.host()
-            .with_mut_storage(|storage| {
-                let k = Rc::new(xdr::LedgerKey::Account(xdr::LedgerKeyAccount {
-                    account_id: issuer_id.clone(),
-                }));
-
-                if !storage.has(
-                    &k,
-                    soroban_env_host::budget::AsBudget::as_budget(self.host()),
-                )? {
-                    let v = Rc::new(xdr::LedgerEntry {
-                        data: xdr::LedgerEntryData::Account(xdr::AccountEntry {
-                            account_id: issuer_id.clone(),
-                            balance: 0,
-                            flags: 0,
-                            home_domain: Default::default(),
-                            inflation_dest: None,
-                            num_sub_entries: 0,
-                            seq_num: xdr::SequenceNumber(0),
-                            thresholds: xdr::Thresholds([1; 4]),
-                            signers: xdr::VecM::default(),
-                            ext: xdr::AccountEntryExt::V0,
-                        }),
-                        last_modified_ledger_seq: 0,
-                        ext: xdr::LedgerEntryExt::V0,
-                    });
-                    storage.put(
-                        &k,
-                        &v,
-                        soroban_env_host::budget::AsBudget::as_budget(self.host()),
-                    )?
-                }
-                Ok(())
-            })
-            .unwrap();
+        self.create_account(&issuer_id);
 
         let asset = xdr::Asset::CreditAlphanum4(xdr::AlphaNum4 {
             asset_code: xdr::AssetCode4(random()),
@@ -719,6 +834,46 @@ impl Env {
         Address::from_contract_id(&token_id)
     }
 
+    /// Creates the account in the ledger, if it does not exist, with the
+    /// account's key as its only signer.
+    pub(crate) fn create_account(&self, account_id: &xdr::AccountId) {
+        self.host()
+            .with_mut_storage(|storage| {
+                let k = Rc::new(xdr::LedgerKey::Account(xdr::LedgerKeyAccount {
+                    account_id: account_id.clone(),
+                }));
+
+                if !storage.has(
+                    &k,
+                    soroban_env_host::budget::AsBudget::as_budget(self.host()),
+                )? {
+                    let v = Rc::new(xdr::LedgerEntry {
+                        data: xdr::LedgerEntryData::Account(xdr::AccountEntry {
+                            account_id: account_id.clone(),
+                            balance: 0,
+                            flags: 0,
+                            home_domain: Default::default(),
+                            inflation_dest: None,
+                            num_sub_entries: 0,
+                            seq_num: xdr::SequenceNumber(0),
+                            thresholds: xdr::Thresholds([1; 4]),
+                            signers: xdr::VecM::default(),
+                            ext: xdr::AccountEntryExt::V0,
+                        }),
+                        last_modified_ledger_seq: 0,
+                        ext: xdr::LedgerEntryExt::V0,
+                    });
+                    storage.put(
+                        &k,
+                        &v,
+                        soroban_env_host::budget::AsBudget::as_budget(self.host()),
+                    )?
+                }
+                Ok(())
+            })
+            .unwrap();
+    }
+
     fn register_contract_with_optional_contract_id_and_executable<'a>(
         &self,
         contract_id: impl Into<Option<&'a Address>>,
@@ -985,6 +1140,163 @@ impl Env {
             .collect()
     }
 
+    /// Returns the authorizations that were recorded during the last contract
+    /// invocation, as the tree of invocations that each address authorized.
+    ///
+    /// Unlike [`auths`][Self::auths], which lists each authorized invocation
+    /// separately, the tree shows which authorized invocations were made
+    /// within others, such as a token transfer made within a swap.
+    ///
+    /// Authorizations are recorded while all auths are mocked with
+    /// [`mock_all_auths`][Self::mock_all_auths]. Invokers, that are authorized
+    /// implicitly, are not included.
+    ///
+    /// ### Examples
+    /// ```
+    /// use soroban_sdk::{
+    ///     contractimpl, testutils::{Address as _, MockAuthInvoke}, Address, Env, IntoVal,
+    /// };
+    ///
+    /// pub struct Contract;
+    ///
+    /// #[contractimpl]
+    /// impl Contract {
+    ///     pub fn transfer(env: Env, address: Address, amount: i128) {
+    ///         address.require_auth();
+    ///     }
+    /// }
+    ///
+    /// # #[cfg(feature = "testutils")]
+    /// # fn main() {
+    ///     let env = Env::default();
+    ///     let contract_id = env.register_contract(None, Contract);
+    ///     let client = ContractClient::new(&env, &contract_id);
+    ///     env.mock_all_auths();
+    ///     let address = Address::random(&env);
+    ///     client.transfer(&address, &1000_i128);
+    ///
+    ///     let auths = env.auths_tree();
+    ///     assert_eq!(auths.len(), 1);
+    ///     assert_eq!(auths[0].0, address);
+    ///     auths[0].1.assert_matches(&MockAuthInvoke {
+    ///         contract: &contract_id,
+    ///         fn_name: "transfer",
+    ///         args: (&address, 1000_i128).into_val(&env),
+    ///         sub_invokes: &[],
+    ///     });
+    /// # }
+    /// # #[cfg(not(feature = "testutils"))]
+    /// # fn main() { }
+    /// ```
+    pub fn auths_tree(&self) -> std::vec::Vec<(Address, AuthorizedInvocation)> {
+        self.env_impl
+            .get_recorded_auth_payloads()
+            .expect("auths are recorded only while all auths are mocked")
+            .iter()
+            .filter_map(|payload| {
+                let address = payload.address.as_ref()?;
+                Some((
+                    Address::try_from_val(self, address).unwrap(),
+                    AuthorizedInvocation::from_xdr(self, &payload.invocation),
+                ))
+            })
+            .collect()
+    }
+
+    /// Asserts that the authorizations recorded during the last contract
+    /// invocation, as returned by [`auths_tree`][Self::auths_tree], match the
+    /// expected addresses and invocation trees, in order.
+    ///
+    /// ### Panics
+    ///
+    /// If the authorizations do not match, with a diff of the expected and
+    /// recorded authorizations.
+    pub fn assert_auths_tree(&self, expected: &[(&Address, &MockAuthInvoke)]) {
+        let expected = expected
+            .iter()
+            .map(|(address, invoke)| ((*address).clone(), AuthorizedInvocation::from(*invoke)))
+            .collect::<std::vec::Vec<_>>();
+        let actual = self.auths_tree();
+        if actual != expected {
+            panic!(
+                "authorizations do not match (- expected, + actual):\n{}",
+                crate::testutils::auth_tree::diff_lines(
+                    &crate::testutils::auth_tree::write_auths_lines(&expected),
+                    &crate::testutils::auth_tree::write_auths_lines(&actual),
+                )
+            );
+        }
+    }
+
+    /// Records the authorizations that are required by the invocation made in
+    /// `f`, without changing the environment.
+    ///
+    /// The function is called with a copy of the environment that has
+    /// authorization recording enabled, as with
+    /// [`mock_all_auths`][Self::mock_all_auths]. Clients used in the function
+    /// must be created with the environment passed to it. Changes made during
+    /// the invocation, including to nonces, are discarded with the copy.
+    ///
+    /// Returns an unsigned [`ContractAuth`] for each address that required
+    /// authorization, containing the full tree of invocations authorized. If
+    /// `f` makes more than one invocation, the authorizations required by the
+    /// last one are returned.
+    ///
+    /// The returned authorizations can be signed with
+    /// [`ContractAuthBuilder`][crate::testutils::ContractAuthBuilder] and set
+    /// with [`set_auths`][Self::set_auths], to replay the invocation with
+    /// authorization enforced.
+    ///
+    /// ### Examples
+    /// ```
+    /// use soroban_sdk::{contractimpl, testutils::Address as _, Address, Env};
+    ///
+    /// pub struct HelloContract;
+    ///
+    /// #[contractimpl]
+    /// impl HelloContract {
+    ///     pub fn hello(env: Env, from: Address) {
+    ///         from.require_auth();
+    ///     }
+    /// }
+    ///
+    /// # #[cfg(feature = "testutils")]
+    /// # fn main() {
+    ///     let env = Env::default();
+    ///     let contract_id = env.register_contract(None, HelloContract);
+    ///     let addr = Address::random(&env);
+    ///
+    ///     let auths = env.record_auths(|env| {
+    ///         HelloContractClient::new(env, &contract_id).hello(&addr);
+    ///     });
+    ///     assert_eq!(auths.len(), 1);
+    ///     assert_eq!(
+    ///         auths[0].root_invocation.function_name,
+    ///         "hello".try_into().unwrap()
+    ///     );
+    /// # }
+    /// # #[cfg(not(feature = "testutils"))]
+    /// # fn main() { }
+    /// ```
+    pub fn record_auths(&self, f: impl FnOnce(&Env)) -> std::vec::Vec<ContractAuth> {
+        let env = self.deep_clone();
+        env.env_impl.switch_to_recording_auth();
+        f(&env);
+        env.env_impl
+            .get_recorded_auth_payloads()
+            .unwrap()
+            .into_iter()
+            .map(|payload| ContractAuth {
+                address_with_nonce: payload
+                    .address
+                    .zip(payload.nonce)
+                    .map(|(address, nonce)| xdr::AddressWithNonce { address, nonce }),
+                root_invocation: payload.invocation,
+                signature_args: Default::default(),
+            })
+            .collect()
+    }
+
     /// Invokes the special `__check_auth` function of contracts that implement
     /// the custom account interface.
     ///
@@ -1174,6 +1486,8 @@ impl Env {
         let env = Env {
             env_impl,
             snapshot: Some(rs.clone()),
+            events_offset: Default::default(),
+            ledger_close_time: Default::default(),
         };
         env.ledger().set(info);
         env
@@ -1224,6 +1538,10 @@ impl Env {
             env_impl,
             #[cfg(any(test, feature = "testutils"))]
             snapshot: None,
+            #[cfg(any(test, feature = "testutils"))]
+            events_offset: Default::default(),
+            #[cfg(any(test, feature = "testutils"))]
+            ledger_close_time: Default::default(),
         }
     }
 }
@@ -1266,6 +1584,10 @@ impl internal::EnvBase for Env {
             env_impl: self.env_impl.deep_clone(),
             #[cfg(any(test, feature = "testutils"))]
             snapshot: self.snapshot.clone(),
+            #[cfg(any(test, feature = "testutils"))]
+            events_offset: Rc::new(Cell::new(self.events_offset.get())),
+            #[cfg(any(test, feature = "testutils"))]
+            ledger_close_time: Rc::new(Cell::new(self.ledger_close_time.get())),
         }
     }
 
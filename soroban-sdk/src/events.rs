@@ -58,6 +58,16 @@ impl Debug for Events {
 
 pub trait Topics: IntoVal<Env, Vec<RawVal>> {}
 
+/// Event is implemented by types that are published as events, such as types
+/// defined with [`contractevent`][crate::contractevent].
+pub trait Event {
+    /// Returns the topics of the event.
+    fn topics(&self, env: &Env) -> Vec<RawVal>;
+
+    /// Returns the data of the event.
+    fn data(&self, env: &Env) -> RawVal;
+}
+
 impl TryFromVal<Env, ()> for Vec<RawVal> {
     type Error = ConversionError;
 
@@ -116,38 +126,163 @@ impl Events {
         internal::Env::contract_event(env, topics.into_val(env).to_object(), data.into_val(env))
             .unwrap_infallible();
     }
+
+    /// Publish an event defined by a type that implements [Event], such as
+    /// types defined with [`contractevent`][crate::contractevent].
+    #[inline(always)]
+    pub fn publish_event<E>(&self, event: &E)
+    where
+        E: Event,
+    {
+        let env = self.env();
+        internal::Env::contract_event(env, event.topics(env).to_object(), event.data(env))
+            .unwrap_infallible();
+    }
 }
 
 #[cfg(any(test, feature = "testutils"))]
 use crate::{testutils, xdr, Address, BytesN, TryIntoVal};
 
+#[cfg(any(test, feature = "testutils"))]
+impl Events {
+    /// Returns the contract events published after the given position in the
+    /// list of events recorded by the host, and the length of that list.
+    fn all_after(&self, offset: usize) -> (Vec<(Address, Vec<RawVal>, RawVal)>, usize) {
+        let env = self.env();
+        let mut vec = Vec::new(env);
+        let events = env.host().get_events().unwrap().0;
+        let len = events.len();
+        events.into_iter().skip(offset).for_each(|e| {
+            if let xdr::ContractEvent {
+                type_: xdr::ContractEventType::Contract,
+                contract_id: Some(contract_id),
+                body: xdr::ContractEventBody::V0(xdr::ContractEventV0 { topics, data }),
+                ..
+            } = e.event
+            {
+                vec.push_back((
+                    Address::from_contract_id(&BytesN::from_array(env, &contract_id.0)),
+                    topics.try_into_val(env).unwrap(),
+                    data.try_into_val(env).unwrap(),
+                ))
+            }
+        });
+        (vec, len)
+    }
+
+    /// Formats an event for display in assertion failures, with the topics and
+    /// data rendered as [`ScVal`][xdr::ScVal]s.
+    fn fmt_event(&self, contract_id: &Address, topics: &Vec<RawVal>, data: &RawVal) -> String {
+        let env = self.env();
+        let topics = xdr::ScVal::try_from(topics);
+        let data = xdr::ScVal::try_from_val(env, data);
+        format!("{contract_id:?}\n    topics: {topics:?}\n    data: {data:?}")
+    }
+}
+
 #[cfg(any(test, feature = "testutils"))]
 #[cfg_attr(feature = "docs", doc(cfg(feature = "testutils")))]
 impl testutils::Events for Events {
     fn all(&self) -> Vec<(crate::Address, Vec<RawVal>, RawVal)> {
+        self.all_after(self.env().events_offset.get()).0
+    }
+
+    fn all_of<E: testutils::ContractEvent>(&self) -> std::vec::Vec<(Address, E)> {
+        let env = self.env();
+        self.all()
+            .into_iter_unchecked()
+            .filter_map(|(contract_id, topics, data)| {
+                E::try_from_event(env, &topics, &data)
+                    .ok()
+                    .map(|event| (contract_id, event))
+            })
+            .collect()
+    }
+
+    fn clear(&self) {
+        self.take();
+    }
+
+    fn take(&self) -> Vec<(Address, Vec<RawVal>, RawVal)> {
+        let offset = &self.env().events_offset;
+        let (events, len) = self.all_after(offset.get());
+        offset.set(len);
+        events
+    }
+
+    fn checkpoint(&self) -> testutils::EventsCheckpoint {
+        let len = self.env().host().get_events().unwrap().0.len();
+        testutils::EventsCheckpoint(len)
+    }
+
+    fn since(
+        &self,
+        checkpoint: &testutils::EventsCheckpoint,
+    ) -> Vec<(Address, Vec<RawVal>, RawVal)> {
+        let offset = self.env().events_offset.get().max(checkpoint.0);
+        self.all_after(offset).0
+    }
+
+    fn from_contract(&self, contract_id: &Address) -> Vec<(Address, Vec<RawVal>, RawVal)> {
         let env = self.env();
         let mut vec = Vec::new(env);
-        self.env()
-            .host()
-            .get_events()
-            .unwrap()
-            .0
-            .into_iter()
-            .for_each(|e| {
-                if let xdr::ContractEvent {
-                    type_: xdr::ContractEventType::Contract,
-                    contract_id: Some(contract_id),
-                    body: xdr::ContractEventBody::V0(xdr::ContractEventV0 { topics, data }),
-                    ..
-                } = e.event
-                {
-                    vec.push_back((
-                        Address::from_contract_id(&BytesN::from_array(env, &contract_id.0)),
-                        topics.try_into_val(env).unwrap(),
-                        data.try_into_val(env).unwrap(),
-                    ))
-                }
-            });
+        for event in self.all().into_iter_unchecked() {
+            if &event.0 == contract_id {
+                vec.push_back(event);
+            }
+        }
         vec
     }
+
+    fn with_topic_prefix<T>(&self, prefix: T) -> Vec<(Address, Vec<RawVal>, RawVal)>
+    where
+        T: IntoVal<Env, Vec<RawVal>>,
+    {
+        let env = self.env();
+        let prefix: Vec<RawVal> = prefix.into_val(env);
+        let mut vec = Vec::new(env);
+        for event in self.all().into_iter_unchecked() {
+            if event.1.len() >= prefix.len() && event.1.slice(..prefix.len()) == prefix {
+                vec.push_back(event);
+            }
+        }
+        vec
+    }
+
+    fn assert_contains<T, D>(&self, contract_id: &Address, topics: T, data: D)
+    where
+        T: IntoVal<Env, Vec<RawVal>>,
+        D: IntoVal<Env, RawVal>,
+    {
+        let env = self.env();
+        let expected: (Address, Vec<RawVal>, RawVal) = (
+            contract_id.clone(),
+            topics.into_val(env),
+            data.into_val(env),
+        );
+        let all = self.all();
+        if all.contains(&expected) {
+            return;
+        }
+        let (contract_id, topics, data) = &expected;
+        let mut msg = format!(
+            "expected event was not published\nexpected:\n  {}\npublished:",
+            self.fmt_event(contract_id, topics, data)
+        );
+        if all.is_empty() {
+            msg.push_str("\n  (none)");
+        }
+        for (i, (contract_id, topics, data)) in all.iter_unchecked().enumerate() {
+            msg.push_str(&format!(
+                "\n  {i}: {}",
+                self.fmt_event(&contract_id, &topics, &data)
+            ));
+        }
+        panic!("{msg}");
+    }
+
+    fn assert_contains_event<E: Event>(&self, contract_id: &Address, event: &E) {
+        let env = self.env();
+        self.assert_contains(contract_id, event.topics(env), event.data(env));
+    }
 }
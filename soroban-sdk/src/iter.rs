@@ -1,11 +1,61 @@
 //! Iterators for use with collections like [Map], [Set], [Vec].
+//!
+//! Collections are created in an [Env], and so cannot implement
+//! [FromIterator]. Instead they implement [FromIteratorIn], and iterators are
+//! collected into them with [CollectIn::collect_in].
+//!
+//! ### Examples
+//!
+//! ```
+//! use soroban_sdk::{iter::CollectIn, vec, Env, Map, Vec};
+//!
+//! let env = Env::default();
+//! let vec = vec![&env, 1, 2, 3];
+//!
+//! let doubled: Vec<u32> = vec.iter_unchecked().map(|x| x * 2).collect_in(&env);
+//! assert_eq!(doubled, vec![&env, 2, 4, 6]);
+//!
+//! let squares: Map<u32, u32> = vec.iter_unchecked().map(|x| (x, x * x)).collect_in(&env);
+//! assert_eq!(squares.get_unchecked(3), Ok(9));
+//! ```
 #[cfg(doc)]
-use crate::{Map, Set, Vec};
+use crate::{Bytes, Map, Set, Vec};
 
 use core::fmt::Debug;
 use core::iter::FusedIterator;
 use core::marker::PhantomData;
 
+use crate::Env;
+
+/// FromIteratorIn is implemented by collections that can be created in an
+/// [Env] from an iterator, as [FromIterator] is for collections that need no
+/// environment.
+///
+/// It is implemented by [Vec], [Map], [Set] and [Bytes], and is usually used
+/// through [CollectIn::collect_in].
+pub trait FromIteratorIn<A>: Sized {
+    /// Creates a collection in the environment from the items of the
+    /// iterator.
+    fn from_iter_in<I: IntoIterator<Item = A>>(env: &Env, iter: I) -> Self;
+}
+
+/// CollectIn collects iterators into collections created in an [Env].
+///
+/// It is implemented for all iterators.
+pub trait CollectIn: Iterator + Sized {
+    /// Collects the items of the iterator into a collection created in the
+    /// environment.
+    ///
+    /// Equivalent to [Iterator::collect], for collections that implement
+    /// [FromIteratorIn].
+    #[inline(always)]
+    fn collect_in<B: FromIteratorIn<Self::Item>>(self, env: &Env) -> B {
+        B::from_iter_in(env, self)
+    }
+}
+
+impl<I: Iterator> CollectIn for I {}
+
 pub trait UncheckedEnumerable<I, T, E> {
     fn unchecked(self) -> UncheckedIter<I, T, E>;
 }
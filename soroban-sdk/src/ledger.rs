@@ -92,7 +92,7 @@ impl Ledger {
 }
 
 #[cfg(any(test, feature = "testutils"))]
-use crate::testutils;
+use crate::testutils::{self, Ledger as _};
 
 #[cfg(any(test, feature = "testutils"))]
 #[cfg_attr(feature = "docs", doc(cfg(feature = "testutils")))]
@@ -114,4 +114,70 @@ impl testutils::Ledger for Ledger {
         let env = self.env();
         env.host().with_mut_ledger_info(f).unwrap();
     }
+
+    fn close_time(&self) -> u64 {
+        let env = self.env();
+        env.ledger_close_time
+            .get()
+            .unwrap_or(testutils::DEFAULT_LEDGER_CLOSE_TIME)
+    }
+
+    fn set_close_time(&self, seconds: u64) {
+        let env = self.env();
+        env.ledger_close_time.set(Some(seconds));
+    }
+
+    fn advance_by_seconds(&self, seconds: u64) {
+        self.with_mut(|li| {
+            li.timestamp = li
+                .timestamp
+                .checked_add(seconds)
+                .expect("ledger timestamp overflow");
+        });
+    }
+
+    fn advance_ledgers(&self, n: u32) {
+        let seconds = self
+            .close_time()
+            .checked_mul(n.into())
+            .expect("ledger timestamp overflow");
+        self.with_mut(|li| {
+            li.sequence_number = li
+                .sequence_number
+                .checked_add(n)
+                .expect("ledger sequence number overflow");
+            li.timestamp = li
+                .timestamp
+                .checked_add(seconds)
+                .expect("ledger timestamp overflow");
+        });
+    }
+
+    fn jump_to_timestamp(&self, timestamp: u64) {
+        let current = self.get().timestamp;
+        if timestamp < current {
+            panic!("cannot jump to timestamp {timestamp} before the ledger timestamp {current}");
+        }
+        self.with_mut(|li| li.timestamp = timestamp);
+    }
+
+    fn with_ledger<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        // Restores the ledger info and close time when dropped, so that they
+        // are restored even if the function panics.
+        struct Restore<'a>(&'a Ledger, Option<testutils::LedgerInfo>, Option<u64>);
+        impl Drop for Restore<'_> {
+            fn drop(&mut self) {
+                if let Some(li) = self.1.take() {
+                    self.0.set(li);
+                }
+                self.0.env().ledger_close_time.set(self.2);
+            }
+        }
+        let close_time = self.env().ledger_close_time.get();
+        let _restore = Restore(self, Some(self.get()), close_time);
+        f()
+    }
 }
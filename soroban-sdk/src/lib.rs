@@ -297,6 +297,57 @@ pub use soroban_sdk_macros::contractimport;
 /// # #[cfg(not(feature = "testutils"))]
 /// # fn main() { }
 /// ```
+///
+/// ### Constructors
+///
+/// A public function named `__constructor` is the contract's constructor. The
+/// constructor is invoked when the contract is deployed with
+/// [`deploy::DeployerWithCurrentContract::deploy_with_constructor`], or
+/// registered with [`Env::register_contract_with_constructor`] or
+/// [`Env::register_contract_wasm_with_constructor`].
+///
+/// The constructor runs exactly once. The deployer invokes it with the salt
+/// the contract was deployed with preceding its arguments, and it panics
+/// unless the contract's ID is derived from the invoking contract's ID and the
+/// salt, or if it has been invoked before. The first invocation is recorded in
+/// the contract's storage under the reserved key `__constructor`.
+///
+/// The constructor is not included in the generated client. It is included in
+/// the contract's spec with its arguments, without the salt, and is marked in
+/// the contract's meta with the `constructor` key.
+///
+/// ```
+/// use soroban_sdk::{contractimpl, Address, Env, Symbol};
+///
+/// pub struct Contract;
+///
+/// #[contractimpl]
+/// impl Contract {
+///     pub fn __constructor(env: Env, admin: Address) {
+///         env.storage().set(&Symbol::short("admin"), &admin);
+///     }
+///
+///     pub fn admin(env: Env) -> Address {
+///         env.storage().get(&Symbol::short("admin")).unwrap()
+///     }
+/// }
+///
+/// #[test]
+/// fn test() {
+/// # }
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+/// #     use soroban_sdk::testutils::Address as _;
+///     let env = Env::default();
+///     let admin = Address::random(&env);
+///     let contract_id = env.register_contract_with_constructor(None, Contract, (admin.clone(),));
+///     let client = ContractClient::new(&env, &contract_id);
+///
+///     assert_eq!(client.admin(), admin);
+/// }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
 pub use soroban_sdk_macros::contractimpl;
 
 /// Adds a serialized SCMetaEntry::SCMetaV0 to the WASM contracts custom section
@@ -494,6 +545,157 @@ pub use soroban_sdk_macros::contractmeta;
 /// ```
 pub use soroban_sdk_macros::contracttype;
 
+/// Generates a typed key space for storing data with
+/// [Storage][crate::storage::Storage].
+///
+/// The enum is replaced with a module of the same name containing one key type
+/// for each variant. Each variant must declare the type of value stored under
+/// it with a `value` attribute. Values of any other type cannot be stored or
+/// retrieved using the key, and attempting to do so will fail to compile.
+///
+/// Keys are stored as the same values that the enum would be stored as if it
+/// were a [`contracttype`]. Any attributes on the enum, such as derives, are
+/// applied to each key type.
+///
+/// There are some constraints on the types that are supported:
+/// - Enums with unit variants are supported.
+/// - Enums with tuple-like variants are supported. The tuple fields must be of
+/// types that are convertible to and from `RawVal`, and must implement
+/// `Clone`.
+/// - Enums with integer values and struct-like variants are not supported.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{contractimpl, contractstorage, Address, Env};
+///
+/// #[contractstorage]
+/// #[derive(Clone, Debug, Eq, PartialEq)]
+/// pub enum DataKey {
+///     #[value(Address)]
+///     Admin,
+///     #[value(i128)]
+///     Balance(Address),
+/// }
+///
+/// pub struct Contract;
+///
+/// #[contractimpl]
+/// impl Contract {
+///     pub fn set_admin(env: Env, admin: Address) {
+///         env.storage().set(&DataKey::Admin, &admin);
+///     }
+///
+///     pub fn mint(env: Env, to: Address, amount: i128) {
+///         let admin: Address = env.storage().get(&DataKey::Admin).unwrap();
+///         admin.require_auth();
+///         let key = DataKey::Balance(to);
+///         let balance = env.storage().get(&key).unwrap_or(0);
+///         env.storage().set(&key, &(balance + amount));
+///     }
+///
+///     pub fn balance(env: Env, id: Address) -> i128 {
+///         env.storage().get(&DataKey::Balance(id)).unwrap_or(0)
+///     }
+/// }
+///
+/// #[test]
+/// fn test() {
+/// # }
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+/// #     use soroban_sdk::testutils::Address as _;
+///     let env = Env::default();
+///     let contract_id = env.register_contract(None, Contract);
+///     let client = ContractClient::new(&env, &contract_id);
+///
+///     let admin = Address::random(&env);
+///     let user = Address::random(&env);
+///     client.set_admin(&admin);
+///     client.mock_all_auths().mint(&user, &10);
+///     assert_eq!(client.balance(&user), 10);
+/// }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+pub use soroban_sdk_macros::contractstorage;
+
+/// Generates a contract event from a struct.
+///
+/// Fields marked with the `topic` attribute are published as the event's
+/// topics, and the remaining fields are published as the event's data.
+///
+/// The first topic of the event is its name as a [`Symbol`], followed by the
+/// topic fields in the order they are declared. At most three fields may be
+/// topics. The name is the name of the struct converted to snake case, or the
+/// value of the `name` argument. The data of the event is a map of the data
+/// fields, keyed by their names.
+///
+/// The struct has a `publish` function generated, that publishes the event
+/// from the currently executing contract. The struct also implements
+/// [`events::Event`], and [`testutils::ContractEvent`] for decoding events
+/// returned by [`testutils::Events::all`], or decoding events of the type
+/// with [`testutils::Events::all_of`].
+///
+/// The event is included in the contract's spec as a struct spec entry, as the
+/// spec has no entry for events. The entry has a field for each field of the
+/// event, and each field's doc begins with `topic` or `data` describing how
+/// the field is published.
+///
+/// There are some constraints on the types that are supported:
+/// - Structs with named fields are supported. The fields must be of types that
+/// are convertible to and from `RawVal`.
+/// - Structs with generics, tuple structs, unit structs, and enums are not
+/// supported.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{contractevent, contractimpl, Address, Env};
+///
+/// #[contractevent]
+/// #[derive(Clone, Debug, Eq, PartialEq)]
+/// pub struct Transfer {
+///     #[topic]
+///     pub from: Address,
+///     #[topic]
+///     pub to: Address,
+///     pub amount: i128,
+/// }
+///
+/// pub struct Contract;
+///
+/// #[contractimpl]
+/// impl Contract {
+///     pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+///         Transfer { from, to, amount }.publish(&env);
+///     }
+/// }
+///
+/// #[test]
+/// fn test() {
+/// # }
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+/// #     use soroban_sdk::testutils::{Address as _, Events as _};
+///     let env = Env::default();
+///     let contract_id = env.register_contract(None, Contract);
+///     let client = ContractClient::new(&env, &contract_id);
+///
+///     let from = Address::random(&env);
+///     let to = Address::random(&env);
+///     client.transfer(&from, &to, &10);
+///
+///     assert_eq!(
+///         env.events().all_of::<Transfer>(),
+///         [(contract_id, Transfer { from, to, amount: 10 })],
+///     );
+/// }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+pub use soroban_sdk_macros::contractevent;
+
 /// Generates a client for a contract trait.
 ///
 /// Can be used to create clients for contracts that live outside the current
@@ -669,11 +871,12 @@ pub mod iter;
 pub mod ledger;
 pub mod logging;
 mod map;
+pub mod math;
 mod set;
 pub mod storage;
 pub mod token;
 mod vec;
-pub use address::Address;
+pub use address::{Address, AddressKind};
 pub use bytes::{Bytes, BytesN};
 pub use map::Map;
 pub use set::Set;
@@ -3,15 +3,22 @@
 //! See [`log`][crate::log] for how to conveniently log debug events.
 use core::fmt::Debug;
 
-use crate::{env::internal::EnvBase, Env, RawVal};
+use crate::{env::internal::EnvBase, unwrap::UnwrapInfallible, Env, Map, RawVal, Symbol};
 
 /// Log a debug event.
 ///
-/// Takes a [Env], a literal string, and an optional trailing sequence of
-/// arguments that may be any value that are convertible to [`RawVal`]. The
-/// string and arguments are appended as-is to the log, as the body of a
-/// structured diagnostic event. Such events may be emitted from the host as
-/// auxiliary diagnostic XDR, or converted to strings later for debugging.
+/// Takes a [Env], an optional level, a literal string, an optional trailing
+/// sequence of arguments that may be any value that are convertible to
+/// [`RawVal`], and an optional sequence of named fields following a `;`. The
+/// string, arguments, and fields are appended as-is to the log, as the body of
+/// a structured diagnostic event. Such events may be emitted from the host as
+/// auxiliary diagnostic XDR, or converted to log records later for debugging.
+///
+/// The level is one of the variants of [`Level`], followed by a `:`. Logs
+/// without a level are logged at [`Level::Debug`]. Logs without a level or
+/// fields are logged as the string followed by the arguments, the same as
+/// [`Logger::log`], and logs with either are logged with
+/// [`Logger::log_with_fields`].
 ///
 /// `log!` statements are only enabled in non optimized builds that have
 /// `debug-assertions` enabled. To enable `debug-assertions` add the following
@@ -24,6 +31,9 @@ use crate::{env::internal::EnvBase, Env, RawVal};
 /// debug-assertions = true
 /// ```
 ///
+/// Levels can be disabled at compile time with the `log-max-level-*` features
+/// of the SDK. See [`STATIC_MAX_LEVEL`].
+///
 /// [custom profiles]:
 ///     https://doc.rust-lang.org/cargo/reference/profiles.html#custom-profiles
 ///
@@ -50,39 +60,157 @@ use crate::{env::internal::EnvBase, Env, RawVal};
 /// log!(&env, "a log entry", value, Symbol::short("another"));
 /// ```
 ///
+/// Log a string with a level, values, and fields:
+///
+/// ```
+/// use soroban_sdk::{log, Symbol, Env};
+///
+/// let env = Env::default();
+///
+/// let value = 5;
+/// log!(&env, Warn: "a log entry", value; kind = Symbol::short("another"));
+/// ```
+///
 /// Assert on logs in tests:
 ///
 /// ```
 /// # #[cfg(feature = "testutils")]
 /// # {
-/// use soroban_sdk::{log, Symbol, Env};
+/// use soroban_sdk::{log, logging::Level, xdr::ScVal, Symbol, Env};
 ///
 /// let env = Env::default();
 ///
 /// let value = 5;
-/// log!(&env, "a log entry", value, Symbol::short("another"));
+/// log!(&env, Info: "a log entry", value, Symbol::short("another"));
 ///
 /// use soroban_sdk::testutils::Logger;
-/// let logentry = env.logger().all().last().unwrap().clone();
-/// assert!(logentry.contains("[\"a log entry\", 5, another]"));
+/// let record = env.logger().all().last().unwrap().clone();
+/// assert_eq!(record.level, Level::Info);
+/// assert_eq!(record.msg, "a log entry");
+/// assert_eq!(record.args[0], ScVal::I32(5));
 /// # }
 /// ```
 #[macro_export]
 macro_rules! log {
-    ($env:expr, $fmt:literal $(,)?) => {
-        if cfg!(debug_assertions) {
-            $env.logger().log($fmt, &[]);
+    ($env:expr, $level:ident: $fmt:literal $(, $args:expr)* $(; $($key:ident = $val:expr),*)? $(,)?) => {
+        if cfg!(debug_assertions) && $crate::logging::Level::$level.is_enabled() {
+            $env.logger().log_with_fields(
+                $crate::logging::Level::$level,
+                $fmt,
+                &[
+                    $(
+                        <_ as $crate::IntoVal<$crate::Env, $crate::RawVal>>::into_val(&$args, $env)
+                    ),*
+                ],
+                &[
+                    $($(
+                        (
+                            stringify!($key),
+                            <_ as $crate::IntoVal<$crate::Env, $crate::RawVal>>::into_val(&$val, $env),
+                        )
+                    ),*)?
+                ],
+            );
         }
     };
-    ($env:expr, $fmt:literal, $($args:expr),* $(,)?) => {
-        if cfg!(debug_assertions) {
+    ($env:expr, $fmt:literal $(, $args:expr)* $(,)?) => {
+        if cfg!(debug_assertions) && $crate::logging::Level::Debug.is_enabled() {
             $env.logger().log($fmt, &[
                 $(
-                    <_ as $crate::IntoVal<Env, $crate::RawVal>>::into_val(&$args, $env)
+                    <_ as $crate::IntoVal<$crate::Env, $crate::RawVal>>::into_val(&$args, $env)
                 ),*
             ]);
         }
     };
+    ($env:expr, $fmt:literal $(, $args:expr)*; $($key:ident = $val:expr),* $(,)?) => {
+        $crate::log!($env, Debug: $fmt $(, $args)*; $($key = $val),*)
+    };
+}
+
+/// Level is the severity of a logged event.
+///
+/// Levels are ordered from the most severe, [`Level::Error`], to the least
+/// severe, [`Level::Trace`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[repr(u32)]
+pub enum Level {
+    /// A failure that the contract cannot recover from.
+    Error = 1,
+    /// An unexpected condition that the contract recovers from.
+    Warn = 2,
+    /// A notable step in the contract's execution.
+    Info = 3,
+    /// Detail for debugging, and the level of logs that have no level.
+    Debug = 4,
+    /// Fine-grained detail, such as each iteration of a loop.
+    Trace = 5,
+}
+
+/// The least severe level that is logged, or `None` if logging is disabled.
+///
+/// Defaults to [`Level::Trace`], logging all levels. Less severe levels can be
+/// disabled at compile time by enabling one of the following features of the
+/// SDK, in which case logs at the disabled levels are compiled out:
+///
+/// - `log-max-level-off`
+/// - `log-max-level-error`
+/// - `log-max-level-warn`
+/// - `log-max-level-info`
+/// - `log-max-level-debug`
+///
+/// If more than one feature is enabled, the most restrictive applies.
+pub const STATIC_MAX_LEVEL: Option<Level> = if cfg!(feature = "log-max-level-off") {
+    None
+} else if cfg!(feature = "log-max-level-error") {
+    Some(Level::Error)
+} else if cfg!(feature = "log-max-level-warn") {
+    Some(Level::Warn)
+} else if cfg!(feature = "log-max-level-info") {
+    Some(Level::Info)
+} else if cfg!(feature = "log-max-level-debug") {
+    Some(Level::Debug)
+} else {
+    Some(Level::Trace)
+};
+
+impl Level {
+    /// Returns if the level is enabled by [`STATIC_MAX_LEVEL`].
+    #[inline(always)]
+    pub const fn is_enabled(self) -> bool {
+        match STATIC_MAX_LEVEL {
+            Some(max) => self as u32 <= max as u32,
+            None => false,
+        }
+    }
+
+    /// Returns the name of the level, that is logged with events at the level.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+
+    #[cfg(any(test, feature = "testutils"))]
+    fn from_name(s: &str) -> Option<Level> {
+        match s {
+            "error" => Some(Level::Error),
+            "warn" => Some(Level::Warn),
+            "info" => Some(Level::Info),
+            "debug" => Some(Level::Debug),
+            "trace" => Some(Level::Trace),
+            _ => None,
+        }
+    }
+}
+
+impl core::fmt::Display for Level {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 /// Logger logs debug events.
@@ -111,48 +239,309 @@ impl Logger {
     /// Log a debug event.
     ///
     /// Takes a literal string and a sequence of trailing values to add
-    /// as a log entry in the diagnostic event stream.
+    /// as a log entry in the diagnostic event stream, at [`Level::Debug`].
+    ///
+    /// The entry is the string followed by the values, without a level or
+    /// fields.
     ///
     /// See [`log`][crate::log] for how to conveniently log debug events.
     #[inline(always)]
     pub fn log(&self, msg: &'static str, args: &[RawVal]) {
-        if cfg!(debug_assertions) {
+        if cfg!(debug_assertions) && Level::Debug.is_enabled() {
             let env = self.env();
             env.log_from_slice(msg, args).unwrap();
         }
     }
+
+    /// Log an event at a level, with named fields.
+    ///
+    /// Takes a level, a literal string, a sequence of trailing values, and a
+    /// sequence of named values to add as a log entry in the diagnostic event
+    /// stream. Field names must be valid [`Symbol`]s.
+    ///
+    /// The entry is logged with the level, the fields as a [`Map`], and the
+    /// values as a [`Vec`][crate::Vec], which
+    /// [`testutils::Logger`][crate::testutils::Logger] parses into log records.
+    ///
+    /// See [`log`][crate::log] for how to conveniently log events.
+    #[inline(always)]
+    pub fn log_with_fields(
+        &self,
+        level: Level,
+        msg: &'static str,
+        args: &[RawVal],
+        fields: &[(&'static str, RawVal)],
+    ) {
+        if cfg!(debug_assertions) && level.is_enabled() {
+            let env = self.env();
+            let mut map = Map::<Symbol, RawVal>::new(env);
+            for (k, v) in fields {
+                map.set(Symbol::new(env, k), *v);
+            }
+            let args = env.vec_new_from_slice(args).unwrap_infallible();
+            let vals = [
+                Symbol::short(level.as_str()).to_raw(),
+                map.to_raw(),
+                args.to_raw(),
+            ];
+            env.log_from_slice(msg, &vals).unwrap();
+        }
+    }
 }
 
 #[cfg(any(test, feature = "testutils"))]
-use crate::testutils;
+use crate::{testutils, xdr, Address, BytesN};
+#[cfg(any(test, feature = "testutils"))]
+use core::fmt::Write;
 
+/// An event in the diagnostic events that is relevant to logs.
 #[cfg(any(test, feature = "testutils"))]
-#[cfg_attr(feature = "docs", doc(cfg(feature = "testutils")))]
-impl testutils::Logger for Logger {
-    fn all(&self) -> std::vec::Vec<String> {
-        use crate::xdr::{
-            ContractEventBody, ContractEventType, ScSymbol, ScVal, ScVec, StringM, VecM,
-        };
+enum LogEntry {
+    /// A contract function was called.
+    Call { contract_id: String, func: String },
+    /// A contract function returned.
+    Return,
+    /// An event was logged.
+    Log(testutils::LogRecord),
+}
+
+#[cfg(any(test, feature = "testutils"))]
+impl Logger {
+    fn entries(&self) -> std::vec::Vec<LogEntry> {
+        use xdr::{ContractEventBody, ContractEventType, ScVal};
         let env = self.env();
-        let log_sym = ScSymbol(StringM::try_from("log").unwrap());
-        let log_topics = ScVec(VecM::try_from(vec![ScVal::Symbol(log_sym)]).unwrap());
         env.host()
             .get_events()
             .unwrap()
             .0
             .into_iter()
             .filter_map(|e| match (&e.event.type_, &e.event.body) {
-                (ContractEventType::Diagnostic, ContractEventBody::V0(ce))
-                    if &ce.topics == &log_topics =>
-                {
-                    Some(format!("{}", &e))
+                (ContractEventType::Diagnostic, ContractEventBody::V0(ce)) => {
+                    match ce.topics.as_slice() {
+                        [ScVal::Symbol(s)] if s.0.as_slice() == b"log" => {
+                            let contract_id = e.event.contract_id.as_ref().map(|contract_id| {
+                                Address::from_contract_id(&BytesN::from_array(env, &contract_id.0))
+                            });
+                            Some(LogEntry::Log(parse_record(contract_id, &ce.data)))
+                        }
+                        [ScVal::Symbol(s), ScVal::Bytes(contract_id), ScVal::Symbol(func)]
+                            if s.0.as_slice() == b"fn_call" =>
+                        {
+                            let contract_id = <[u8; 32]>::try_from(contract_id.as_slice())
+                                .map(contract_strkey)
+                                .unwrap_or_else(|_| format!("{contract_id:?}"));
+                            Some(LogEntry::Call {
+                                contract_id,
+                                func: func.0.to_string_lossy(),
+                            })
+                        }
+                        [ScVal::Symbol(s), ..] if s.0.as_slice() == b"fn_return" => {
+                            Some(LogEntry::Return)
+                        }
+                        _ => None,
+                    }
                 }
                 _ => None,
             })
-            .collect::<std::vec::Vec<_>>()
+            .collect()
+    }
+}
+
+#[cfg(any(test, feature = "testutils"))]
+#[cfg_attr(feature = "docs", doc(cfg(feature = "testutils")))]
+impl testutils::Logger for Logger {
+    fn all(&self) -> std::vec::Vec<testutils::LogRecord> {
+        self.entries()
+            .into_iter()
+            .filter_map(|entry| match entry {
+                LogEntry::Log(record) => Some(record),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn lines(&self) -> std::vec::Vec<String> {
+        // Log records are listed beneath the contract invocation that logged
+        // them, indented by the depth of the invocation.
+        let mut lines = std::vec::Vec::new();
+        let mut depth = 0;
+        for entry in self.entries() {
+            let indent = "  ".repeat(depth);
+            match entry {
+                LogEntry::Call { contract_id, func } => {
+                    lines.push(format!("{indent}{contract_id}::{func}"));
+                    depth += 1;
+                }
+                LogEntry::Return => depth = depth.saturating_sub(1),
+                LogEntry::Log(record) if depth > 0 => {
+                    lines.push(format!("{indent}[{}] {}", record.level, record.message()));
+                }
+                LogEntry::Log(record) => lines.push(format!("{indent}{record}")),
+            }
+        }
+        lines
     }
 
     fn print(&self) {
-        std::println!("{}", self.all().join("\n"))
+        for line in self.lines() {
+            std::println!("{line}");
+        }
+    }
+}
+
+#[cfg(any(test, feature = "testutils"))]
+impl testutils::LogRecord {
+    /// Returns the message with each `{}` replaced by the next of the args.
+    ///
+    /// Args that are left over once all `{}` have been replaced are appended
+    /// to the message, followed by the fields as `name=value`. Values are
+    /// rendered in a readable form, such as addresses as strkeys and bytes as
+    /// hex.
+    pub fn message(&self) -> String {
+        let mut out = String::new();
+        let mut args = self.args.iter();
+        let mut parts = self.msg.split("{}");
+        if let Some(first) = parts.next() {
+            out.push_str(first);
+        }
+        for part in parts {
+            match args.next() {
+                Some(arg) => write_val(&mut out, arg),
+                None => out.push_str("{}"),
+            }
+            out.push_str(part);
+        }
+        for arg in args {
+            out.push(' ');
+            write_val(&mut out, arg);
+        }
+        for (name, val) in &self.fields {
+            let _ = write!(out, " {name}=");
+            write_val(&mut out, val);
+        }
+        out
+    }
+}
+
+#[cfg(any(test, feature = "testutils"))]
+impl core::fmt::Display for testutils::LogRecord {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "[{}] ", self.level)?;
+        if let Some(contract_id) = &self.contract_id {
+            let contract_id = xdr::ScVal::try_from(contract_id).map_err(|_| core::fmt::Error)?;
+            let mut out = String::new();
+            write_val(&mut out, &contract_id);
+            write!(f, "{out}: ")?;
+        }
+        write!(f, "{}", self.message())
+    }
+}
+
+#[cfg(any(test, feature = "testutils"))]
+fn contract_strkey(contract_id: [u8; 32]) -> String {
+    use stellar_strkey::{Contract, Strkey};
+    Strkey::Contract(Contract(contract_id)).to_string()
+}
+
+/// Writes a value in a readable form, with addresses as strkeys, symbols
+/// as-is, bytes as hex, and vecs and maps with their elements recursively
+/// written in the same form.
+#[cfg(any(test, feature = "testutils"))]
+pub(crate) fn write_val(out: &mut String, v: &xdr::ScVal) {
+    use stellar_strkey::{ed25519, Strkey};
+    use xdr::{AccountId, PublicKey, ScAddress, ScVal, Uint256};
+    let _ = match v {
+        ScVal::Void => write!(out, "()"),
+        ScVal::Bool(b) => write!(out, "{b}"),
+        ScVal::U32(n) => write!(out, "{n}"),
+        ScVal::I32(n) => write!(out, "{n}"),
+        ScVal::U64(n) => write!(out, "{n}"),
+        ScVal::I64(n) => write!(out, "{n}"),
+        ScVal::U128(n) => write!(out, "{}", (u128::from(n.hi) << 64) | u128::from(n.lo)),
+        ScVal::I128(n) => write!(out, "{}", (i128::from(n.hi) << 64) | i128::from(n.lo)),
+        ScVal::Symbol(s) => write!(out, "{}", s.0.to_string_lossy()),
+        ScVal::String(s) => write!(out, "{:?}", s.0.to_string_lossy()),
+        ScVal::Bytes(b) => {
+            out.push_str("0x");
+            b.iter().try_for_each(|b| write!(out, "{b:02x}"))
+        }
+        ScVal::Address(ScAddress::Contract(contract_id)) => {
+            write!(out, "{}", contract_strkey(contract_id.0))
+        }
+        ScVal::Address(ScAddress::Account(AccountId(PublicKey::PublicKeyTypeEd25519(
+            Uint256(key),
+        )))) => write!(
+            out,
+            "{}",
+            Strkey::PublicKeyEd25519(ed25519::PublicKey(*key)).to_string()
+        ),
+        ScVal::Vec(Some(vec)) => {
+            out.push('[');
+            for (i, v) in vec.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_val(out, v);
+            }
+            out.push(']');
+            Ok(())
+        }
+        ScVal::Map(Some(map)) => {
+            out.push('{');
+            for (i, e) in map.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_val(out, &e.key);
+                out.push_str(": ");
+                write_val(out, &e.val);
+            }
+            out.push('}');
+            Ok(())
+        }
+        v => write!(out, "{v:?}"),
+    };
+}
+
+/// Parses a log record from the data of a log event.
+///
+/// The data is expected to be the message followed by a level, a map of fields
+/// and a vec of args, as logged by [`Logger::log_with_fields`]. Data of other
+/// log events is parsed as the message followed by args, at [`Level::Debug`].
+#[cfg(any(test, feature = "testutils"))]
+fn parse_record(contract_id: Option<Address>, data: &xdr::ScVal) -> testutils::LogRecord {
+    use xdr::ScVal;
+    let to_msg = |v: &ScVal| match v {
+        ScVal::String(s) => s.0.to_string_lossy(),
+        ScVal::Symbol(s) => s.0.to_string_lossy(),
+        v => format!("{v:?}"),
+    };
+    let (msg, vals) = match data {
+        ScVal::Vec(Some(vals)) if !vals.is_empty() => (to_msg(&vals[0]), &vals[1..]),
+        data => (to_msg(data), &[][..]),
+    };
+    match vals {
+        [ScVal::Symbol(level), ScVal::Map(Some(fields)), ScVal::Vec(Some(args))] => {
+            if let Some(level) = Level::from_name(&level.0.to_string_lossy()) {
+                return testutils::LogRecord {
+                    contract_id,
+                    level,
+                    msg,
+                    args: args.to_vec(),
+                    fields: fields
+                        .iter()
+                        .map(|e| (to_msg(&e.key), e.val.clone()))
+                        .collect(),
+                };
+            }
+        }
+        _ => (),
+    }
+    testutils::LogRecord {
+        contract_id,
+        level: Level::Debug,
+        msg,
+        args: vals.to_vec(),
+        fields: std::vec::Vec::new(),
     }
 }
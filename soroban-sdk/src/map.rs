@@ -1,9 +1,14 @@
 use core::{
-    cmp::Ordering, convert::Infallible, fmt::Debug, iter::FusedIterator, marker::PhantomData,
+    cmp::Ordering,
+    convert::Infallible,
+    fmt::Debug,
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::{Bound, RangeBounds},
 };
 
 use crate::{
-    iter::{UncheckedEnumerable, UncheckedIter},
+    iter::{FromIteratorIn, UncheckedEnumerable, UncheckedIter},
     unwrap::UnwrapInfallible,
 };
 
@@ -87,6 +92,27 @@ macro_rules! map {
 ///     map![&env, (2, 20), (1, 10)],
 /// )
 /// ```
+///
+/// Maps can be navigated in the order of their keys, and ranges of their keys
+/// iterated, without iterating the whole map.
+///
+/// ```
+/// use soroban_sdk::{Env, Map, map};
+///
+/// let env = Env::default();
+/// let mut map = map![&env, (10, 1), (20, 2), (30, 3), (40, 4)];
+/// assert_eq!(map.first_key_value(), Some(Ok((10, 1))));
+/// assert_eq!(map.lower_bound(20), Some(Ok((20, 2))));
+/// assert_eq!(map.upper_bound(20), Some(Ok((30, 3))));
+///
+/// let mut range = map.range(15..40);
+/// assert_eq!(range.next(), Some(Ok((20, 2))));
+/// assert_eq!(range.next(), Some(Ok((30, 3))));
+/// assert_eq!(range.next(), None);
+///
+/// assert_eq!(map.pop_last(), Some(Ok((40, 4))));
+/// assert_eq!(map.len(), 3);
+/// ```
 #[derive(Clone)]
 pub struct Map<K, V> {
     env: Env,
@@ -386,6 +412,107 @@ where
         Vec::<V>::try_from_val(env, &vec).unwrap()
     }
 
+    /// Returns the key-value pair with the smallest key, or [None] if the map
+    /// is empty.
+    #[inline(always)]
+    pub fn first_key_value(&self) -> Option<Result<(K, V), ConversionError>> {
+        let key = self.env.map_min_key(self.obj).unwrap_infallible();
+        Some(self.key_value(found(key)?))
+    }
+
+    /// Returns the key-value pair with the largest key, or [None] if the map
+    /// is empty.
+    #[inline(always)]
+    pub fn last_key_value(&self) -> Option<Result<(K, V), ConversionError>> {
+        let key = self.env.map_max_key(self.obj).unwrap_infallible();
+        Some(self.key_value(found(key)?))
+    }
+
+    /// Returns the key-value pair with the smallest key that is greater than
+    /// or equal to the key, or [None] if there is no such key.
+    ///
+    /// The key does not need to be in the map.
+    #[inline(always)]
+    pub fn lower_bound(&self, k: K) -> Option<Result<(K, V), ConversionError>> {
+        let key = self.lower_bound_raw(k.into_val(&self.env))?;
+        Some(self.key_value(key))
+    }
+
+    /// Returns the key-value pair with the smallest key that is greater than
+    /// the key, or [None] if there is no such key.
+    ///
+    /// The key does not need to be in the map.
+    #[inline(always)]
+    pub fn upper_bound(&self, k: K) -> Option<Result<(K, V), ConversionError>> {
+        let env = self.env();
+        let key = env
+            .map_next_key(self.obj, k.into_val(env))
+            .unwrap_infallible();
+        Some(self.key_value(found(key)?))
+    }
+
+    /// Removes and returns the key-value pair with the smallest key, or
+    /// [None] if the map is empty.
+    #[inline(always)]
+    pub fn pop_first(&mut self) -> Option<Result<(K, V), ConversionError>> {
+        let key = self.env.map_min_key(self.obj).unwrap_infallible();
+        Some(self.pop_raw(found(key)?))
+    }
+
+    /// Removes and returns the key-value pair with the largest key, or [None]
+    /// if the map is empty.
+    #[inline(always)]
+    pub fn pop_last(&mut self) -> Option<Result<(K, V), ConversionError>> {
+        let key = self.env.map_max_key(self.obj).unwrap_infallible();
+        Some(self.pop_raw(found(key)?))
+    }
+
+    /// Returns an iterator over the key-value pairs with keys in the range,
+    /// in order of the keys.
+    ///
+    /// The iterator finds each key with a lookup of the next key in the map,
+    /// so iterating a range costs in proportion to the number of keys in the
+    /// range rather than in the map. If the start of the range is greater
+    /// than its end, the iterator is empty.
+    ///
+    /// The iterator iterates the map as it was when the iterator was created.
+    pub fn range<R: RangeBounds<K>>(&self, r: R) -> MapRange<K, V> {
+        let env = self.env();
+        let front = match r.start_bound() {
+            Bound::Included(k) => self.lower_bound_raw(k.into_val(env)),
+            Bound::Excluded(k) => found(
+                env.map_next_key(self.obj, k.into_val(env))
+                    .unwrap_infallible(),
+            ),
+            Bound::Unbounded => found(env.map_min_key(self.obj).unwrap_infallible()),
+        };
+        let back = match r.end_bound() {
+            Bound::Included(k) => {
+                let k = k.into_val(env);
+                if env.map_has(self.obj, k).unwrap_infallible().into() {
+                    Some(k)
+                } else {
+                    found(env.map_prev_key(self.obj, k).unwrap_infallible())
+                }
+            }
+            Bound::Excluded(k) => found(
+                env.map_prev_key(self.obj, k.into_val(env))
+                    .unwrap_infallible(),
+            ),
+            Bound::Unbounded => found(env.map_max_key(self.obj).unwrap_infallible()),
+        };
+        let bounds = match (front, back) {
+            (Some(front), Some(back)) if env.obj_cmp(front, back).unwrap_infallible() <= 0 => {
+                Some((front, back))
+            }
+            _ => None,
+        };
+        MapRange {
+            map: self.clone(),
+            bounds,
+        }
+    }
+
     pub fn iter(&self) -> MapIter<K, V>
     where
         K: Clone,
@@ -417,6 +544,46 @@ where
     }
 }
 
+impl<K, V> Map<K, V>
+where
+    K: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+    V: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+    /// Returns the key and value of the key in the map.
+    fn key_value(&self, key: RawVal) -> Result<(K, V), ConversionError> {
+        let env = self.env();
+        let value = env.map_get(self.obj, key).unwrap_infallible();
+        let k = K::try_from_val(env, &key).map_err(|_| ConversionError)?;
+        let v = V::try_from_val(env, &value).map_err(|_| ConversionError)?;
+        Ok((k, v))
+    }
+
+    /// Removes the key in the map, returning its key and value.
+    fn pop_raw(&mut self, key: RawVal) -> Result<(K, V), ConversionError> {
+        let key_value = self.key_value(key);
+        self.obj = self.env.map_del(self.obj, key).unwrap_infallible();
+        key_value
+    }
+
+    /// Returns the smallest key in the map that is greater than or equal to
+    /// the key.
+    fn lower_bound_raw(&self, key: RawVal) -> Option<RawVal> {
+        let env = self.env();
+        if env.map_has(self.obj, key).unwrap_infallible().into() {
+            Some(key)
+        } else {
+            found(env.map_next_key(self.obj, key).unwrap_infallible())
+        }
+    }
+}
+
+/// Returns the key returned by a host function that finds a key in a map, or
+/// [None] if the host function returned an error because there is no such
+/// key.
+fn found(key: RawVal) -> Option<RawVal> {
+    Error::try_from(key).is_err().then_some(key)
+}
+
 impl<K, V> IntoIterator for Map<K, V>
 where
     K: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
@@ -430,6 +597,22 @@ where
     }
 }
 
+impl<K, V> FromIteratorIn<(K, V)> for Map<K, V>
+where
+    K: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+    V: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+    /// Creates a [Map] from the key-value pairs. If a key occurs more than
+    /// once, the last value for the key is kept.
+    fn from_iter_in<I: IntoIterator<Item = (K, V)>>(env: &Env, iter: I) -> Self {
+        let mut map = Map::new(env);
+        for (k, v) in iter {
+            map.set(k, v);
+        }
+        map
+    }
+}
+
 #[derive(Clone)]
 pub struct MapIter<K, V>(Map<K, V>);
 
@@ -502,6 +685,61 @@ where
     // TODO: Implement other functions as optimizations.
 }
 
+/// MapRange is an iterator over the key-value pairs of a [Map] with keys in a
+/// range, created by [Map::range].
+#[derive(Clone)]
+pub struct MapRange<K, V> {
+    map: Map<K, V>,
+    // The first and last keys of the range that are yet to be iterated, or
+    // None if the range has been exhausted.
+    bounds: Option<(RawVal, RawVal)>,
+}
+
+impl<K, V> Iterator for MapRange<K, V>
+where
+    K: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+    V: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+    type Item = Result<(K, V), ConversionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (front, back) = self.bounds?;
+        let env = self.map.env();
+        self.bounds = if env.obj_cmp(front, back).unwrap_infallible() == 0 {
+            None
+        } else {
+            found(env.map_next_key(self.map.obj, front).unwrap_infallible())
+                .map(|next| (next, back))
+        };
+        Some(self.map.key_value(front))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for MapRange<K, V>
+where
+    K: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+    V: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (front, back) = self.bounds?;
+        let env = self.map.env();
+        self.bounds = if env.obj_cmp(front, back).unwrap_infallible() == 0 {
+            None
+        } else {
+            found(env.map_prev_key(self.map.obj, back).unwrap_infallible())
+                .map(|prev| (front, prev))
+        };
+        Some(self.map.key_value(back))
+    }
+}
+
+impl<K, V> FusedIterator for MapRange<K, V>
+where
+    K: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+    V: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+}
+
 impl<K, V> FusedIterator for MapIter<K, V>
 where
     K: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
@@ -633,4 +871,132 @@ mod test {
         let values = map.values();
         assert_eq!(values, vec![&env, 0, 10, 20, 30, 40]);
     }
+
+    #[test]
+    fn test_collect_in() {
+        use crate::iter::CollectIn;
+        let env = Env::default();
+
+        let map: Map<u32, u32> = (0..5).rev().map(|x| (x, x * 10)).collect_in(&env);
+        assert_eq!(map, map![&env, (0, 0), (1, 10), (2, 20), (3, 30), (4, 40)]);
+
+        // The last value of a repeated key is kept.
+        let map: Map<u32, u32> = [(1, 10), (2, 20), (1, 30)].into_iter().collect_in(&env);
+        assert_eq!(map, map![&env, (1, 30), (2, 20)]);
+    }
+
+    #[test]
+    fn test_first_and_last_key_value() {
+        let env = Env::default();
+
+        let map = map![&env, (2, 20), (1, 10), (3, 30)];
+        assert_eq!(map.first_key_value(), Some(Ok((1, 10))));
+        assert_eq!(map.last_key_value(), Some(Ok((3, 30))));
+
+        let map = Map::<u32, u32>::new(&env);
+        assert_eq!(map.first_key_value(), None);
+        assert_eq!(map.last_key_value(), None);
+    }
+
+    #[test]
+    fn test_lower_and_upper_bound() {
+        let env = Env::default();
+
+        let map = map![&env, (10, 1), (20, 2), (30, 3)];
+        assert_eq!(map.lower_bound(0), Some(Ok((10, 1))));
+        assert_eq!(map.lower_bound(10), Some(Ok((10, 1))));
+        assert_eq!(map.lower_bound(11), Some(Ok((20, 2))));
+        assert_eq!(map.lower_bound(30), Some(Ok((30, 3))));
+        assert_eq!(map.lower_bound(31), None);
+
+        assert_eq!(map.upper_bound(0), Some(Ok((10, 1))));
+        assert_eq!(map.upper_bound(10), Some(Ok((20, 2))));
+        assert_eq!(map.upper_bound(29), Some(Ok((30, 3))));
+        assert_eq!(map.upper_bound(30), None);
+
+        let map = Map::<u32, u32>::new(&env);
+        assert_eq!(map.lower_bound(0), None);
+        assert_eq!(map.upper_bound(0), None);
+    }
+
+    #[test]
+    fn test_pop_first_and_last() {
+        let env = Env::default();
+
+        let mut map = map![&env, (1, 10), (2, 20), (3, 30)];
+        assert_eq!(map.pop_first(), Some(Ok((1, 10))));
+        assert_eq!(map.pop_last(), Some(Ok((3, 30))));
+        assert_eq!(map, map![&env, (2, 20)]);
+        assert_eq!(map.pop_last(), Some(Ok((2, 20))));
+        assert_eq!(map.pop_first(), None);
+        assert_eq!(map.pop_last(), None);
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn test_range() {
+        let env = Env::default();
+
+        let map = map![&env, (10, 1), (20, 2), (30, 3), (40, 4)];
+        let keys = |range: MapRange<u32, u32>| {
+            let mut keys = Vec::new(&env);
+            for kv in range {
+                keys.push_back(kv.unwrap().0);
+            }
+            keys
+        };
+
+        assert_eq!(keys(map.range(..)), vec![&env, 10, 20, 30, 40]);
+        assert_eq!(keys(map.range(20..)), vec![&env, 20, 30, 40]);
+        assert_eq!(keys(map.range(15..)), vec![&env, 20, 30, 40]);
+        assert_eq!(keys(map.range(..30)), vec![&env, 10, 20]);
+        assert_eq!(keys(map.range(..=30)), vec![&env, 10, 20, 30]);
+        assert_eq!(keys(map.range(..=35)), vec![&env, 10, 20, 30]);
+        assert_eq!(keys(map.range(20..40)), vec![&env, 20, 30]);
+        assert_eq!(keys(map.range(20..=40)), vec![&env, 20, 30, 40]);
+        assert_eq!(
+            keys(map.range((Bound::Excluded(20), Bound::Excluded(40)))),
+            vec![&env, 30]
+        );
+        assert_eq!(
+            keys(map.range((Bound::Excluded(5), Bound::Included(10)))),
+            vec![&env, 10]
+        );
+
+        // Empty ranges.
+        assert_eq!(keys(map.range(21..30)), vec![&env]);
+        assert_eq!(keys(map.range(30..30)), vec![&env]);
+        assert_eq!(keys(map.range(41..)), vec![&env]);
+        assert_eq!(keys(map.range(..10)), vec![&env]);
+        assert_eq!(
+            keys(map.range((Bound::Included(40), Bound::Included(10)))),
+            vec![&env]
+        );
+        assert_eq!(keys(Map::new(&env).range(..)), vec![&env]);
+    }
+
+    #[test]
+    fn test_range_double_ended() {
+        let env = Env::default();
+
+        let map = map![&env, (0, 0), (1, 10), (2, 20), (3, 30), (4, 40)];
+        let mut range = map.range(1..4);
+        assert_eq!(range.next_back(), Some(Ok((3, 30))));
+        assert_eq!(range.next(), Some(Ok((1, 10))));
+        assert_eq!(range.next_back(), Some(Ok((2, 20))));
+        assert_eq!(range.next(), None);
+        assert_eq!(range.next_back(), None);
+
+        let mut range = map.range(..).rev();
+        assert_eq!(range.next(), Some(Ok((4, 40))));
+        assert_eq!(range.next(), Some(Ok((3, 30))));
+
+        // The range iterates the map as it was when it was created.
+        let mut map = map;
+        let mut range = map.range(3..);
+        map.pop_last();
+        assert_eq!(range.next(), Some(Ok((3, 30))));
+        assert_eq!(range.next(), Some(Ok((4, 40))));
+        assert_eq!(range.next(), None);
+    }
 }
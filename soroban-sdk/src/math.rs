@@ -0,0 +1,328 @@
+//! Math contains fixed-point arithmetic for the integer types used for token
+//! amounts and prices.
+//!
+//! A fixed-point value is an integer that represents a number multiplied by
+//! a constant scale. For example, Stellar assets have 7 decimal places, so an
+//! amount of `1.5` is represented by the integer `15_000_000` with a scale of
+//! `10_000_000`. Many tokens use 18 decimal places, with a scale of `10^18`.
+//!
+//! [`FixedPoint`] is implemented for [`i128`], [`u128`], [`I256`] and
+//! [`U256`]. Its functions compute intermediate products at twice the width of
+//! the type, so that they only overflow if the result does not fit in the
+//! type. Results that are not exact are rounded in the direction of the
+//! [`Rounding`], so that contracts can round in their own favour.
+//!
+//! Fixed-point values are plain integers, and are stored in contract types and
+//! passed to and from contract functions as such.
+//!
+//! ### Examples
+//!
+//! ```
+//! use soroban_sdk::math::{FixedPoint, Rounding};
+//!
+//! // 7 decimal places.
+//! const SCALE: i128 = 10_000_000;
+//!
+//! // 250 * 1.2345678 = 308.641950
+//! let amount: i128 = 250 * SCALE;
+//! let price: i128 = 12_345_678;
+//! assert_eq!(amount.mul_div_floor(&price, &SCALE), 3_086_419_500);
+//!
+//! // 1 / 3 rounds down to 0.3333333, and up to 0.3333334.
+//! assert_eq!(SCALE.mul_div_floor(&SCALE, &(3 * SCALE)), 3_333_333);
+//! assert_eq!(SCALE.mul_div_ceil(&SCALE, &(3 * SCALE)), 3_333_334);
+//!
+//! // Intermediate products do not overflow.
+//! let large = i128::MAX / 2;
+//! assert_eq!(large.mul_div_floor(&SCALE, &SCALE), large);
+//!
+//! // sqrt(2) = 1.4142135
+//! assert_eq!((2 * SCALE).sqrt(&SCALE), 14_142_135);
+//!
+//! // 1.1^3 = 1.331
+//! assert_eq!(
+//!     (11 * SCALE / 10).pow_fixed(3, &SCALE, Rounding::Floor),
+//!     13_310_000
+//! );
+//! ```
+use core::cmp::Ordering;
+
+use crate::{
+    num::limbs::{Int, Limbs, Signed},
+    I256, U256,
+};
+
+/// Rounding is the direction in which results that are not exact are
+/// rounded.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Rounding {
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// FixedPoint is implemented by the integer types that fixed-point values are
+/// represented with.
+///
+/// The scale of a fixed-point value is the integer that represents one, and
+/// must be positive.
+pub trait FixedPoint: Sized + private::Sealed {
+    /// Returns `self * y / denominator`, rounded in the direction of the
+    /// rounding, or `None` if the denominator is zero or the result
+    /// overflows.
+    ///
+    /// The product `self * y` is computed at twice the width of the type and
+    /// never overflows.
+    fn checked_mul_div(&self, y: &Self, denominator: &Self, rounding: Rounding) -> Option<Self>;
+
+    /// Returns `self * y / denominator`, rounded down.
+    ///
+    /// ### Panics
+    ///
+    /// If the denominator is zero or the result overflows.
+    fn mul_div_floor(&self, y: &Self, denominator: &Self) -> Self {
+        self.checked_mul_div(y, denominator, Rounding::Floor)
+            .expect("attempt to multiply and divide with overflow or by zero")
+    }
+
+    /// Returns `self * y / denominator`, rounded up.
+    ///
+    /// ### Panics
+    ///
+    /// If the denominator is zero or the result overflows.
+    fn mul_div_ceil(&self, y: &Self, denominator: &Self) -> Self {
+        self.checked_mul_div(y, denominator, Rounding::Ceil)
+            .expect("attempt to multiply and divide with overflow or by zero")
+    }
+
+    /// Returns the square root of the fixed-point value with the scale,
+    /// rounded down, or `None` if the value or scale is negative.
+    ///
+    /// The result is the integer square root of `self * scale`, so with a
+    /// scale of one it is the integer square root of the value.
+    fn checked_sqrt(&self, scale: &Self) -> Option<Self>;
+
+    /// Returns the square root of the fixed-point value with the scale,
+    /// rounded down.
+    ///
+    /// ### Panics
+    ///
+    /// If the value or scale is negative.
+    fn sqrt(&self, scale: &Self) -> Self {
+        self.checked_sqrt(scale)
+            .expect("attempt to take the square root of a negative value")
+    }
+
+    /// Returns the fixed-point value with the scale raised to the power of
+    /// `exp`, or `None` if the scale is not positive or the result overflows.
+    ///
+    /// The power is computed by repeated squaring of the magnitude, with each
+    /// multiplication rounded so that the result is rounded in the direction
+    /// of the rounding. The error of the result grows with the number of
+    /// multiplications.
+    fn checked_pow_fixed(&self, exp: u32, scale: &Self, rounding: Rounding) -> Option<Self>;
+
+    /// Returns the fixed-point value with the scale raised to the power of
+    /// `exp`.
+    ///
+    /// ### Panics
+    ///
+    /// If the scale is not positive or the result overflows.
+    fn pow_fixed(&self, exp: u32, scale: &Self, rounding: Rounding) -> Self {
+        self.checked_pow_fixed(exp, scale, rounding)
+            .expect("attempt to raise to a power with overflow")
+    }
+}
+
+/// The sign and magnitude of an integer. Zero is never negative.
+#[derive(Clone, Copy)]
+struct Parts {
+    negative: bool,
+    magnitude: Limbs,
+}
+
+impl Parts {
+    fn new(negative: bool, magnitude: Limbs) -> Self {
+        Parts {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+}
+
+/// Conversions of the integer types to and from their sign and magnitude.
+trait ToParts: Sized {
+    fn to_parts(&self) -> Parts;
+
+    /// Returns the integer of the parts, or `None` if it does not fit. The
+    /// integer is created in the environment of `self`, if it has one.
+    fn from_parts(&self, parts: Parts) -> Option<Self>;
+}
+
+impl ToParts for u128 {
+    fn to_parts(&self) -> Parts {
+        Parts::new(false, Limbs::from_u128(*self))
+    }
+
+    fn from_parts(&self, parts: Parts) -> Option<Self> {
+        if parts.negative {
+            return None;
+        }
+        parts.magnitude.to_u128()
+    }
+}
+
+impl ToParts for i128 {
+    fn to_parts(&self) -> Parts {
+        Parts::new(*self < 0, Limbs::from_u128(self.unsigned_abs()))
+    }
+
+    fn from_parts(&self, parts: Parts) -> Option<Self> {
+        let magnitude = parts.magnitude.to_u128()?;
+        if parts.negative {
+            // The magnitude of the minimum value is one more than that of the
+            // maximum value.
+            (magnitude <= i128::MIN.unsigned_abs()).then_some((magnitude as i128).wrapping_neg())
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+}
+
+impl ToParts for U256 {
+    fn to_parts(&self) -> Parts {
+        Parts::new(false, self.to_limbs())
+    }
+
+    fn from_parts(&self, parts: Parts) -> Option<Self> {
+        if parts.negative {
+            return None;
+        }
+        Some(U256::from_limbs(&self.env(), parts.magnitude))
+    }
+}
+
+impl ToParts for I256 {
+    fn to_parts(&self) -> Parts {
+        let limbs = self.to_limbs();
+        Parts::new(Signed::is_negative(limbs), Signed::abs(limbs))
+    }
+
+    fn from_parts(&self, parts: Parts) -> Option<Self> {
+        let limbs = if parts.negative {
+            if parts.magnitude.cmp_unsigned(Signed::MIN) == Ordering::Greater {
+                return None;
+            }
+            parts.magnitude.wrapping_neg()
+        } else {
+            if parts.magnitude.high_bit() {
+                return None;
+            }
+            parts.magnitude
+        };
+        Some(I256::from_limbs(&self.env(), limbs))
+    }
+}
+
+fn mul_div(x: Parts, y: Parts, denominator: Parts, rounding: Rounding) -> Option<Parts> {
+    if denominator.magnitude.is_zero() {
+        return None;
+    }
+    let (lo, hi) = x.magnitude.widening_mul(y.magnitude);
+    let (mut q, r) = Limbs::div_rem_wide(lo, hi, denominator.magnitude)?;
+    let negative = x.negative ^ y.negative ^ denominator.negative;
+    // The quotient is rounded towards zero, and inexact results are rounded
+    // away from zero when that is the direction of the rounding.
+    if !r.is_zero() && (rounding == Rounding::Floor) == negative {
+        let (incremented, overflow) = q.overflowing_add(Limbs::ONE);
+        if overflow {
+            return None;
+        }
+        q = incremented;
+    }
+    Some(Parts::new(negative, q))
+}
+
+fn sqrt(x: Parts, scale: Parts) -> Option<Parts> {
+    if x.negative || scale.negative {
+        return None;
+    }
+    let (lo, hi) = x.magnitude.widening_mul(scale.magnitude);
+    Some(Parts::new(false, Limbs::sqrt_wide(lo, hi)))
+}
+
+fn pow(x: Parts, exp: u32, scale: Parts, rounding: Rounding) -> Option<Parts> {
+    if scale.negative || scale.magnitude.is_zero() {
+        return None;
+    }
+    // The magnitude of a negative result is rounded in the opposite direction,
+    // so that the result is rounded in the direction of the rounding.
+    let negative = x.negative && exp & 1 == 1;
+    let rounding = match (negative, rounding) {
+        (false, rounding) => rounding,
+        (true, Rounding::Floor) => Rounding::Ceil,
+        (true, Rounding::Ceil) => Rounding::Floor,
+    };
+    let (mut base, mut exp) = (Parts::new(false, x.magnitude), exp);
+    let mut acc = scale;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            acc = mul_div(acc, base, scale, rounding)?;
+        }
+        exp >>= 1;
+        // Only square the base if it is needed, so that squaring past the
+        // highest bit of the exponent does not report an overflow.
+        if exp > 0 {
+            base = mul_div(base, base, scale, rounding)?;
+        }
+    }
+    Some(Parts::new(negative, acc.magnitude))
+}
+
+macro_rules! impl_fixed_point {
+    ($t:ty) => {
+        impl private::Sealed for $t {}
+
+        impl FixedPoint for $t {
+            fn checked_mul_div(
+                &self,
+                y: &Self,
+                denominator: &Self,
+                rounding: Rounding,
+            ) -> Option<Self> {
+                let parts = mul_div(
+                    self.to_parts(),
+                    y.to_parts(),
+                    denominator.to_parts(),
+                    rounding,
+                )?;
+                self.from_parts(parts)
+            }
+
+            fn checked_sqrt(&self, scale: &Self) -> Option<Self> {
+                let parts = sqrt(self.to_parts(), scale.to_parts())?;
+                self.from_parts(parts)
+            }
+
+            fn checked_pow_fixed(
+                &self,
+                exp: u32,
+                scale: &Self,
+                rounding: Rounding,
+            ) -> Option<Self> {
+                let parts = pow(self.to_parts(), exp, scale.to_parts(), rounding)?;
+                self.from_parts(parts)
+            }
+        }
+    };
+}
+
+impl_fixed_point!(u128);
+impl_fixed_point!(i128);
+impl_fixed_point!(U256);
+impl_fixed_point!(I256);
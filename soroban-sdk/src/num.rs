@@ -2,28 +2,30 @@ use core::{cmp::Ordering, convert::Infallible, fmt::Debug};
 
 use super::{
     env::internal::{Env as _, EnvBase as _, I256Small, I256Val, U256Small, U256Val},
-    ConversionError, Env, RawVal, TryFromVal, TryIntoVal,
+    Bytes, ConversionError, Env, RawVal, TryFromVal, TryIntoVal,
 };
 
 #[cfg(not(target_family = "wasm"))]
 use crate::env::internal::xdr::ScVal;
-use crate::{env::MaybeEnv, unwrap::UnwrapInfallible};
+use crate::{
+    env::MaybeEnv,
+    unwrap::{UnwrapInfallible, UnwrapOptimized},
+    xdr::FromXdr,
+};
+
+pub(crate) mod limbs;
+
+use limbs::{Int, Limbs, Signed, Unsigned};
 
 macro_rules! impl_num_wrapping_val_type {
-    ($wrapper:ident, $val:ty, $small:ty) => {
+    ($(#[$attr:meta])* $wrapper:ident, $val:ty, $small:ty) => {
+        $(#[$attr])*
         #[derive(Clone)]
         pub struct $wrapper {
             env: MaybeEnv,
             val: $val,
         }
 
-        impl Debug for $wrapper {
-            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                // FIXME: properly print it when we have the conversion functions
-                write!(f, "{:?}", self.val.as_raw())
-            }
-        }
-
         impl Eq for $wrapper {}
 
         impl PartialEq for $wrapper {
@@ -159,5 +161,456 @@ macro_rules! impl_num_wrapping_val_type {
     };
 }
 
-impl_num_wrapping_val_type!(U256, U256Val, U256Small);
-impl_num_wrapping_val_type!(I256, I256Val, I256Small);
+impl_num_wrapping_val_type!(
+    /// U256 holds a 256-bit unsigned integer.
+    ///
+    /// Arithmetic is available in panicking, checked, wrapping and saturating
+    /// forms, as for the primitive integer types.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{Env, U256};
+    ///
+    /// let env = Env::default();
+    /// let a = U256::from_u128(&env, u128::MAX);
+    /// let b = a.mul(&U256::from_u128(&env, 4));
+    /// assert!(b > u128::MAX);
+    /// assert_eq!(b.div(&a), 4u32);
+    /// assert_eq!(a.checked_pow(3), None);
+    /// ```
+    U256,
+    U256Val,
+    U256Small
+);
+impl_num_wrapping_val_type!(
+    /// I256 holds a 256-bit signed integer.
+    ///
+    /// Arithmetic is available in panicking, checked, wrapping and saturating
+    /// forms, as for the primitive integer types.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{Env, I256};
+    ///
+    /// let env = Env::default();
+    /// let a = I256::from_i128(&env, i128::MIN);
+    /// let b = a.mul(&a);
+    /// assert!(b > i128::MAX);
+    /// assert_eq!(b.div(&a), i128::MIN);
+    /// assert_eq!(a.shr(127), -1i32);
+    /// ```
+    I256,
+    I256Val,
+    I256Small
+);
+
+macro_rules! impl_num_arith {
+    (
+        $wrapper:ident,
+        $int:ident,
+        $sc_val_type:literal,
+        $prim:ty,
+        $from_prim:ident,
+        $to_prim:ident,
+        [$($cmp_prim:ty),*]
+    ) => {
+        impl Debug for $wrapper {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                #[cfg(target_family = "wasm")]
+                write!(f, "{}(..)", stringify!($wrapper))?;
+                #[cfg(not(target_family = "wasm"))]
+                {
+                    write!(f, "{}(", stringify!($wrapper))?;
+                    $int::write_decimal(self.to_limbs(), f)?;
+                    write!(f, ")")?;
+                }
+                Ok(())
+            }
+        }
+
+        impl TryFromVal<Env, $prim> for $wrapper {
+            type Error = Infallible;
+
+            fn try_from_val(env: &Env, v: &$prim) -> Result<Self, Self::Error> {
+                Ok($wrapper::$from_prim(env, *v))
+            }
+        }
+
+        impl TryFromVal<Env, $wrapper> for $prim {
+            type Error = ConversionError;
+
+            fn try_from_val(_env: &Env, v: &$wrapper) -> Result<Self, Self::Error> {
+                v.$to_prim().ok_or(ConversionError)
+            }
+        }
+
+        $(
+            impl PartialEq<$cmp_prim> for $wrapper {
+                fn eq(&self, other: &$cmp_prim) -> bool {
+                    self.partial_cmp(other) == Some(Ordering::Equal)
+                }
+            }
+
+            impl PartialOrd<$cmp_prim> for $wrapper {
+                fn partial_cmp(&self, other: &$cmp_prim) -> Option<Ordering> {
+                    Some($int::cmp(
+                        self.to_limbs(),
+                        Limbs::$from_prim((*other).into()),
+                    ))
+                }
+            }
+
+            impl PartialEq<$wrapper> for $cmp_prim {
+                fn eq(&self, other: &$wrapper) -> bool {
+                    other.eq(self)
+                }
+            }
+
+            impl PartialOrd<$wrapper> for $cmp_prim {
+                fn partial_cmp(&self, other: &$wrapper) -> Option<Ordering> {
+                    other.partial_cmp(self).map(Ordering::reverse)
+                }
+            }
+        )*
+
+        impl $wrapper {
+            /// Creates a value from a primitive integer.
+            pub fn $from_prim(env: &Env, v: $prim) -> Self {
+                Self::from_limbs(env, Limbs::$from_prim(v))
+            }
+
+            /// Returns the value as a primitive integer, or `None` if it does
+            /// not fit.
+            pub fn $to_prim(&self) -> Option<$prim> {
+                self.to_limbs().$to_prim()
+            }
+
+            /// Creates a value from its 32 byte big-endian representation, in
+            /// two's complement for signed values.
+            ///
+            /// ### Panics
+            ///
+            /// If the bytes are not 32 bytes long.
+            pub fn from_be_bytes(env: &Env, bytes: &Bytes) -> Self {
+                if bytes.len() != 32 {
+                    panic!("256-bit integers are 32 bytes long");
+                }
+                let mut buf = [0u8; 32];
+                bytes.copy_into_slice(&mut buf);
+                Self::from_limbs(env, Limbs::from_be_bytes(&buf))
+            }
+
+            /// Returns the 32 byte big-endian representation of the value, in
+            /// two's complement for signed values.
+            pub fn to_be_bytes(&self) -> Bytes {
+                Bytes::from_array(&self.env(), &self.to_limbs().to_be_bytes())
+            }
+
+            /// Returns `self + other`.
+            ///
+            /// ### Panics
+            ///
+            /// If the result overflows.
+            pub fn add(&self, other: &Self) -> Self {
+                self.checked_add(other)
+                    .expect("attempt to add with overflow")
+            }
+
+            /// Returns `self - other`.
+            ///
+            /// ### Panics
+            ///
+            /// If the result overflows.
+            pub fn sub(&self, other: &Self) -> Self {
+                self.checked_sub(other)
+                    .expect("attempt to subtract with overflow")
+            }
+
+            /// Returns `self * other`.
+            ///
+            /// ### Panics
+            ///
+            /// If the result overflows.
+            pub fn mul(&self, other: &Self) -> Self {
+                self.checked_mul(other)
+                    .expect("attempt to multiply with overflow")
+            }
+
+            /// Returns `self / other`, rounded towards zero.
+            ///
+            /// ### Panics
+            ///
+            /// If `other` is zero, or the result overflows.
+            pub fn div(&self, other: &Self) -> Self {
+                self.assert_nonzero_divisor(other, "attempt to divide by zero");
+                self.checked_op(other, $int::overflowing_div)
+                    .expect("attempt to divide with overflow")
+            }
+
+            /// Returns `self % other`, which has the sign of `self`.
+            ///
+            /// ### Panics
+            ///
+            /// If `other` is zero, or the result overflows.
+            pub fn rem(&self, other: &Self) -> Self {
+                self.assert_nonzero_divisor(
+                    other,
+                    "attempt to calculate the remainder with a divisor of zero",
+                );
+                self.checked_op(other, $int::overflowing_rem)
+                    .expect("attempt to calculate the remainder with overflow")
+            }
+
+            /// Returns `self` raised to the power of `exp`.
+            ///
+            /// ### Panics
+            ///
+            /// If the result overflows.
+            pub fn pow(&self, exp: u32) -> Self {
+                self.checked_pow(exp)
+                    .expect("attempt to raise to a power with overflow")
+            }
+
+            /// Returns `self << bits`.
+            ///
+            /// ### Panics
+            ///
+            /// If `bits` is 256 or more.
+            pub fn shl(&self, bits: u32) -> Self {
+                self.checked_shl(bits)
+                    .expect("attempt to shift left with overflow")
+            }
+
+            /// Returns `self >> bits`, which is an arithmetic shift for signed
+            /// values.
+            ///
+            /// ### Panics
+            ///
+            /// If `bits` is 256 or more.
+            pub fn shr(&self, bits: u32) -> Self {
+                self.checked_shr(bits)
+                    .expect("attempt to shift right with overflow")
+            }
+
+            /// Returns `self + other`, or `None` if the result overflows.
+            pub fn checked_add(&self, other: &Self) -> Option<Self> {
+                self.checked_op(other, $int::overflowing_add)
+            }
+
+            /// Returns `self - other`, or `None` if the result overflows.
+            pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+                self.checked_op(other, $int::overflowing_sub)
+            }
+
+            /// Returns `self * other`, or `None` if the result overflows.
+            pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+                self.checked_op(other, $int::overflowing_mul)
+            }
+
+            /// Returns `self / other`, or `None` if `other` is zero or the
+            /// result overflows.
+            pub fn checked_div(&self, other: &Self) -> Option<Self> {
+                if other.to_limbs().is_zero() {
+                    return None;
+                }
+                self.checked_op(other, $int::overflowing_div)
+            }
+
+            /// Returns `self % other`, or `None` if `other` is zero or the
+            /// result overflows.
+            pub fn checked_rem(&self, other: &Self) -> Option<Self> {
+                if other.to_limbs().is_zero() {
+                    return None;
+                }
+                self.checked_op(other, $int::overflowing_rem)
+            }
+
+            /// Returns `self` raised to the power of `exp`, or `None` if the
+            /// result overflows.
+            pub fn checked_pow(&self, exp: u32) -> Option<Self> {
+                let (r, overflow) = $int::overflowing_pow(self.to_limbs(), exp);
+                (!overflow).then(|| Self::from_limbs(&self.env(), r))
+            }
+
+            /// Returns `self << bits`, or `None` if `bits` is 256 or more.
+            pub fn checked_shl(&self, bits: u32) -> Option<Self> {
+                (bits < 256).then(|| self.wrapping_shl(bits))
+            }
+
+            /// Returns `self >> bits`, or `None` if `bits` is 256 or more.
+            pub fn checked_shr(&self, bits: u32) -> Option<Self> {
+                (bits < 256).then(|| self.wrapping_shr(bits))
+            }
+
+            /// Returns `self + other`, wrapping around on overflow.
+            pub fn wrapping_add(&self, other: &Self) -> Self {
+                self.wrapping_op(other, $int::overflowing_add)
+            }
+
+            /// Returns `self - other`, wrapping around on overflow.
+            pub fn wrapping_sub(&self, other: &Self) -> Self {
+                self.wrapping_op(other, $int::overflowing_sub)
+            }
+
+            /// Returns `self * other`, wrapping around on overflow.
+            pub fn wrapping_mul(&self, other: &Self) -> Self {
+                self.wrapping_op(other, $int::overflowing_mul)
+            }
+
+            /// Returns `self / other`, wrapping around on overflow, which
+            /// only occurs when dividing the minimum signed value by -1.
+            ///
+            /// ### Panics
+            ///
+            /// If `other` is zero.
+            pub fn wrapping_div(&self, other: &Self) -> Self {
+                self.assert_nonzero_divisor(other, "attempt to divide by zero");
+                self.wrapping_op(other, $int::overflowing_div)
+            }
+
+            /// Returns `self % other`, wrapping around on overflow, which
+            /// only occurs when dividing the minimum signed value by -1.
+            ///
+            /// ### Panics
+            ///
+            /// If `other` is zero.
+            pub fn wrapping_rem(&self, other: &Self) -> Self {
+                self.assert_nonzero_divisor(
+                    other,
+                    "attempt to calculate the remainder with a divisor of zero",
+                );
+                self.wrapping_op(other, $int::overflowing_rem)
+            }
+
+            /// Returns `self` raised to the power of `exp`, wrapping around on
+            /// overflow.
+            pub fn wrapping_pow(&self, exp: u32) -> Self {
+                let (r, _) = $int::overflowing_pow(self.to_limbs(), exp);
+                Self::from_limbs(&self.env(), r)
+            }
+
+            /// Returns `self << (bits % 256)`.
+            pub fn wrapping_shl(&self, bits: u32) -> Self {
+                Self::from_limbs(&self.env(), self.to_limbs().shl(bits % 256))
+            }
+
+            /// Returns `self >> (bits % 256)`.
+            pub fn wrapping_shr(&self, bits: u32) -> Self {
+                Self::from_limbs(&self.env(), $int::shr(self.to_limbs(), bits % 256))
+            }
+
+            /// Returns `self + other`, saturating at the minimum or maximum
+            /// value on overflow.
+            pub fn saturating_add(&self, other: &Self) -> Self {
+                let (a, b) = (self.to_limbs(), other.to_limbs());
+                let saturated = if $int::is_negative(b) { $int::MIN } else { $int::MAX };
+                self.saturating_op($int::overflowing_add(a, b), saturated)
+            }
+
+            /// Returns `self - other`, saturating at the minimum or maximum
+            /// value on overflow.
+            pub fn saturating_sub(&self, other: &Self) -> Self {
+                let (a, b) = (self.to_limbs(), other.to_limbs());
+                let saturated = if $int::is_negative(b) { $int::MAX } else { $int::MIN };
+                self.saturating_op($int::overflowing_sub(a, b), saturated)
+            }
+
+            /// Returns `self * other`, saturating at the minimum or maximum
+            /// value on overflow.
+            pub fn saturating_mul(&self, other: &Self) -> Self {
+                let (a, b) = (self.to_limbs(), other.to_limbs());
+                let saturated = if $int::is_negative(a) != $int::is_negative(b) {
+                    $int::MIN
+                } else {
+                    $int::MAX
+                };
+                self.saturating_op($int::overflowing_mul(a, b), saturated)
+            }
+
+            /// Returns `self / other`, saturating at the maximum value on
+            /// overflow, which only occurs when dividing the minimum signed
+            /// value by -1.
+            ///
+            /// ### Panics
+            ///
+            /// If `other` is zero.
+            pub fn saturating_div(&self, other: &Self) -> Self {
+                self.assert_nonzero_divisor(other, "attempt to divide by zero");
+                let (a, b) = (self.to_limbs(), other.to_limbs());
+                self.saturating_op($int::overflowing_div(a, b), $int::MAX)
+            }
+
+            /// Returns `self` raised to the power of `exp`, saturating at the
+            /// minimum or maximum value on overflow.
+            pub fn saturating_pow(&self, exp: u32) -> Self {
+                let a = self.to_limbs();
+                let saturated = if $int::is_negative(a) && exp % 2 == 1 {
+                    $int::MIN
+                } else {
+                    $int::MAX
+                };
+                self.saturating_op($int::overflowing_pow(a, exp), saturated)
+            }
+
+            pub(crate) fn env(&self) -> Env {
+                self.env.clone().try_into().unwrap_optimized()
+            }
+
+            /// Converts the value to limbs by serializing it to its ScVal XDR,
+            /// which for 256-bit integers is the 4 byte type followed by the
+            /// 32 byte big-endian value.
+            pub(crate) fn to_limbs(&self) -> Limbs {
+                let env = self.env();
+                let xdr = env.serialize_to_bytes(self.to_raw()).unwrap_infallible();
+                let xdr = unsafe { Bytes::unchecked_new(env, xdr) };
+                let mut buf = [0u8; 36];
+                xdr.copy_into_slice(&mut buf);
+                let mut value = [0u8; 32];
+                value.copy_from_slice(&buf[4..]);
+                Limbs::from_be_bytes(&value)
+            }
+
+            /// Converts limbs to a value by deserializing its ScVal XDR, so that
+            /// the host stores it as a small value when it fits.
+            pub(crate) fn from_limbs(env: &Env, limbs: Limbs) -> Self {
+                let mut buf = [0u8; 36];
+                buf[..4].copy_from_slice(&($sc_val_type as u32).to_be_bytes());
+                buf[4..].copy_from_slice(&limbs.to_be_bytes());
+                Self::from_xdr(env, &Bytes::from_array(env, &buf)).unwrap_optimized()
+            }
+
+            fn assert_nonzero_divisor(&self, other: &Self, msg: &str) {
+                if other.to_limbs().is_zero() {
+                    panic!("{}", msg);
+                }
+            }
+
+            fn checked_op(&self, other: &Self, op: fn(Limbs, Limbs) -> (Limbs, bool)) -> Option<Self> {
+                let (r, overflow) = op(self.to_limbs(), other.to_limbs());
+                (!overflow).then(|| Self::from_limbs(&self.env(), r))
+            }
+
+            fn wrapping_op(&self, other: &Self, op: fn(Limbs, Limbs) -> (Limbs, bool)) -> Self {
+                let (r, _) = op(self.to_limbs(), other.to_limbs());
+                Self::from_limbs(&self.env(), r)
+            }
+
+            fn saturating_op(&self, (r, overflow): (Limbs, bool), saturated: Limbs) -> Self {
+                Self::from_limbs(&self.env(), if overflow { saturated } else { r })
+            }
+        }
+    };
+}
+
+impl_num_arith!(
+    U256,
+    Unsigned,
+    11,
+    u128,
+    from_u128,
+    to_u128,
+    [u32, u64, u128]
+);
+impl_num_arith!(I256, Signed, 12, i128, from_i128, to_i128, [i32, i64, i128]);
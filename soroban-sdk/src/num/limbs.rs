@@ -0,0 +1,561 @@
+//! Arithmetic on 256-bit integers stored as four 64-bit limbs.
+//!
+//! The host does not provide arithmetic on 256-bit integers, so [`U256`] and
+//! [`I256`] convert their values to limbs, compute in the guest, and convert
+//! the results back. The [`math`] module also uses limbs for the 512-bit
+//! intermediate results of fixed-point arithmetic.
+//!
+//! [`U256`]: crate::U256
+//! [`I256`]: crate::I256
+//! [`math`]: crate::math
+use core::cmp::Ordering;
+
+/// Limbs of a 256-bit integer, least significant first.
+///
+/// Signed integers are stored in two's complement.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct Limbs(pub(crate) [u64; 4]);
+
+impl Limbs {
+    pub(crate) const ZERO: Limbs = Limbs([0, 0, 0, 0]);
+    pub(crate) const ONE: Limbs = Limbs([1, 0, 0, 0]);
+
+    pub(crate) fn from_be_bytes(bytes: &[u8; 32]) -> Self {
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let start = (3 - i) * 8;
+            let mut limb_bytes = [0u8; 8];
+            limb_bytes.copy_from_slice(&bytes[start..start + 8]);
+            *limb = u64::from_be_bytes(limb_bytes);
+        }
+        Limbs(limbs)
+    }
+
+    pub(crate) fn to_be_bytes(self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (i, limb) in self.0.iter().enumerate() {
+            let start = (3 - i) * 8;
+            bytes[start..start + 8].copy_from_slice(&limb.to_be_bytes());
+        }
+        bytes
+    }
+
+    pub(crate) fn from_u128(v: u128) -> Self {
+        Limbs([v as u64, (v >> 64) as u64, 0, 0])
+    }
+
+    pub(crate) fn from_i128(v: i128) -> Self {
+        let ext = if v < 0 { u64::MAX } else { 0 };
+        Limbs([v as u64, (v >> 64) as u64, ext, ext])
+    }
+
+    pub(crate) fn to_u128(self) -> Option<u128> {
+        if self.0[2] == 0 && self.0[3] == 0 {
+            Some(self.low_u128())
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn to_i128(self) -> Option<i128> {
+        // The value fits if the upper limbs are the sign extension of the
+        // lower 128 bits.
+        let v = self.low_u128() as i128;
+        let ext = if v < 0 { u64::MAX } else { 0 };
+        if self.0[2] == ext && self.0[3] == ext {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    fn low_u128(self) -> u128 {
+        (self.0[0] as u128) | ((self.0[1] as u128) << 64)
+    }
+
+    pub(crate) fn is_zero(self) -> bool {
+        self == Self::ZERO
+    }
+
+    /// Returns true if the most significant bit is set, which for signed
+    /// integers is the sign bit.
+    pub(crate) fn high_bit(self) -> bool {
+        self.0[3] >> 63 == 1
+    }
+
+    fn bit(self, i: u32) -> bool {
+        (self.0[(i / 64) as usize] >> (i % 64)) & 1 == 1
+    }
+
+    fn not(self) -> Self {
+        Limbs(self.0.map(|limb| !limb))
+    }
+
+    pub(crate) fn wrapping_neg(self) -> Self {
+        self.not().overflowing_add(Self::ONE).0
+    }
+
+    pub(crate) fn cmp_unsigned(self, other: Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+
+    pub(crate) fn overflowing_add(self, other: Self) -> (Self, bool) {
+        let mut limbs = [0u64; 4];
+        let mut carry = false;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (sum, c1) = self.0[i].overflowing_add(other.0[i]);
+            let (sum, c2) = sum.overflowing_add(carry as u64);
+            *limb = sum;
+            carry = c1 || c2;
+        }
+        (Limbs(limbs), carry)
+    }
+
+    fn overflowing_sub(self, other: Self) -> (Self, bool) {
+        let mut limbs = [0u64; 4];
+        let mut borrow = false;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (diff, b1) = self.0[i].overflowing_sub(other.0[i]);
+            let (diff, b2) = diff.overflowing_sub(borrow as u64);
+            *limb = diff;
+            borrow = b1 || b2;
+        }
+        (Limbs(limbs), borrow)
+    }
+
+    fn leading_zeros(self) -> u32 {
+        let mut zeros = 0;
+        for limb in self.0.iter().rev() {
+            zeros += limb.leading_zeros();
+            if *limb != 0 {
+                break;
+            }
+        }
+        zeros
+    }
+
+    /// Multiplies unsigned integers, returning the low and high limbs of the
+    /// 512-bit product.
+    pub(crate) fn widening_mul(self, other: Self) -> (Self, Self) {
+        let mut product = [0u64; 8];
+        for (i, &a) in self.0.iter().enumerate() {
+            let mut carry = 0u128;
+            for (j, &b) in other.0.iter().enumerate() {
+                let t = (a as u128) * (b as u128) + (product[i + j] as u128) + carry;
+                product[i + j] = t as u64;
+                carry = t >> 64;
+            }
+            product[i + 4] = carry as u64;
+        }
+        (
+            Limbs([product[0], product[1], product[2], product[3]]),
+            Limbs([product[4], product[5], product[6], product[7]]),
+        )
+    }
+
+    fn overflowing_mul(self, other: Self) -> (Self, bool) {
+        let (lo, hi) = self.widening_mul(other);
+        (lo, !hi.is_zero())
+    }
+
+    /// Divides unsigned integers, returning the quotient and remainder.
+    ///
+    /// The divisor must not be zero.
+    fn div_rem(self, divisor: Self) -> (Self, Self) {
+        if let (Some(a), Some(b)) = (self.to_u128(), divisor.to_u128()) {
+            return (Self::from_u128(a / b), Self::from_u128(a % b));
+        }
+        Self::long_div(self, Self::ZERO, divisor)
+    }
+
+    /// Divides the unsigned 512-bit integer of the low and high limbs,
+    /// returning the quotient and remainder, or `None` if the quotient does
+    /// not fit in 256 bits.
+    ///
+    /// The divisor must not be zero.
+    pub(crate) fn div_rem_wide(lo: Self, hi: Self, divisor: Self) -> Option<(Self, Self)> {
+        if hi.is_zero() {
+            Some(lo.div_rem(divisor))
+        } else if hi.cmp_unsigned(divisor) == Ordering::Less {
+            Some(Self::long_div(lo, hi, divisor))
+        } else {
+            None
+        }
+    }
+
+    /// Divides the unsigned 512-bit integer of the low and high limbs by bit
+    /// long division. The high limbs must be less than the divisor.
+    fn long_div(lo: Self, hi: Self, divisor: Self) -> (Self, Self) {
+        let mut quotient = Self::ZERO;
+        let mut remainder = hi;
+        for i in (0..256).rev() {
+            // The remainder is less than the divisor before shifting, so if a
+            // bit is shifted out the shifted remainder exceeds the divisor
+            // and the wrapping subtraction yields the correct remainder.
+            let carry = remainder.high_bit();
+            remainder = remainder.shl(1);
+            remainder.0[0] |= lo.bit(i) as u64;
+            if carry || remainder.cmp_unsigned(divisor) != Ordering::Less {
+                remainder = remainder.overflowing_sub(divisor).0;
+                quotient.0[(i / 64) as usize] |= 1 << (i % 64);
+            }
+        }
+        (quotient, remainder)
+    }
+
+    /// Returns the square root, rounded down, of the unsigned 512-bit integer
+    /// of the low and high limbs.
+    pub(crate) fn sqrt_wide(lo: Self, hi: Self) -> Self {
+        let bits = if hi.is_zero() {
+            256 - lo.leading_zeros()
+        } else {
+            512 - hi.leading_zeros()
+        };
+        if bits == 0 {
+            return Self::ZERO;
+        }
+        // Newton's method converges from above, so start at a power of two
+        // that is at least the square root, the largest of which does not fit
+        // in 256 bits and is replaced by the maximum value.
+        let mut x = if bits > 510 {
+            Limbs([u64::MAX; 4])
+        } else {
+            Self::ONE.shl((bits + 1) / 2)
+        };
+        loop {
+            // A quotient that does not fit exceeds x, so x is the root.
+            let Some((q, _)) = Self::div_rem_wide(lo, hi, x) else {
+                return x;
+            };
+            let (sum, carry) = x.overflowing_add(q);
+            let mut y = sum.shr(1);
+            y.0[3] |= (carry as u64) << 63;
+            if y.cmp_unsigned(x) != Ordering::Less {
+                return x;
+            }
+            x = y;
+        }
+    }
+
+    /// Writes the value, interpreted as unsigned, in decimal.
+    #[cfg(not(target_family = "wasm"))]
+    fn write_unsigned_decimal(self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Split the value into chunks of 19 decimal digits, the most that fit
+        // in a limb, least significant first. 2^256 has 78 decimal digits.
+        const CHUNK: Limbs = Limbs([10_000_000_000_000_000_000, 0, 0, 0]);
+        let mut chunks = [0u64; 5];
+        let mut n = 0;
+        let mut v = self;
+        loop {
+            let (q, r) = v.div_rem(CHUNK);
+            chunks[n] = r.0[0];
+            n += 1;
+            v = q;
+            if v.is_zero() {
+                break;
+            }
+        }
+        write!(f, "{}", chunks[n - 1])?;
+        for chunk in chunks[..n - 1].iter().rev() {
+            write!(f, "{:019}", chunk)?;
+        }
+        Ok(())
+    }
+
+    /// Shifts left by bits, which must be less than 256.
+    pub(crate) fn shl(self, bits: u32) -> Self {
+        let (n, b) = ((bits / 64) as usize, bits % 64);
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate().skip(n) {
+            *limb = self.0[i - n] << b;
+            if b > 0 && i > n {
+                *limb |= self.0[i - n - 1] >> (64 - b);
+            }
+        }
+        Limbs(limbs)
+    }
+
+    /// Shifts right by bits, which must be less than 256, filling with zeros.
+    fn shr(self, bits: u32) -> Self {
+        let (n, b) = ((bits / 64) as usize, bits % 64);
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate().take(4 - n) {
+            *limb = self.0[i + n] >> b;
+            if b > 0 && i + n + 1 < 4 {
+                *limb |= self.0[i + n + 1] << (64 - b);
+            }
+        }
+        Limbs(limbs)
+    }
+}
+
+/// Int is the arithmetic of the unsigned or signed interpretation of limbs.
+///
+/// Division functions require a divisor that is not zero.
+pub(crate) trait Int {
+    const MIN: Limbs;
+    const MAX: Limbs;
+    fn is_negative(v: Limbs) -> bool;
+    fn cmp(a: Limbs, b: Limbs) -> Ordering;
+    fn overflowing_add(a: Limbs, b: Limbs) -> (Limbs, bool);
+    fn overflowing_sub(a: Limbs, b: Limbs) -> (Limbs, bool);
+    fn overflowing_mul(a: Limbs, b: Limbs) -> (Limbs, bool);
+    fn overflowing_div(a: Limbs, b: Limbs) -> (Limbs, bool);
+    fn overflowing_rem(a: Limbs, b: Limbs) -> (Limbs, bool);
+    /// Shifts right by bits, which must be less than 256.
+    fn shr(v: Limbs, bits: u32) -> Limbs;
+
+    fn overflowing_pow(base: Limbs, exp: u32) -> (Limbs, bool) {
+        let (mut base, mut exp) = (base, exp);
+        let mut acc = Limbs::ONE;
+        let mut overflow = false;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                let (r, o) = Self::overflowing_mul(acc, base);
+                acc = r;
+                overflow |= o;
+            }
+            exp >>= 1;
+            // Only square the base if it is needed, so that squaring past the
+            // highest bit of the exponent does not report an overflow.
+            if exp > 0 {
+                let (r, o) = Self::overflowing_mul(base, base);
+                base = r;
+                overflow |= o;
+            }
+        }
+        (acc, overflow)
+    }
+
+    /// Writes the value in decimal.
+    #[cfg(not(target_family = "wasm"))]
+    fn write_decimal(v: Limbs, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if Self::is_negative(v) {
+            f.write_str("-")?;
+            v.wrapping_neg().write_unsigned_decimal(f)
+        } else {
+            v.write_unsigned_decimal(f)
+        }
+    }
+}
+
+/// Unsigned interpretation of limbs.
+pub(crate) struct Unsigned;
+
+impl Int for Unsigned {
+    const MIN: Limbs = Limbs::ZERO;
+    const MAX: Limbs = Limbs([u64::MAX; 4]);
+
+    fn is_negative(_v: Limbs) -> bool {
+        false
+    }
+
+    fn cmp(a: Limbs, b: Limbs) -> Ordering {
+        a.cmp_unsigned(b)
+    }
+
+    fn overflowing_add(a: Limbs, b: Limbs) -> (Limbs, bool) {
+        a.overflowing_add(b)
+    }
+
+    fn overflowing_sub(a: Limbs, b: Limbs) -> (Limbs, bool) {
+        a.overflowing_sub(b)
+    }
+
+    fn overflowing_mul(a: Limbs, b: Limbs) -> (Limbs, bool) {
+        a.overflowing_mul(b)
+    }
+
+    fn overflowing_div(a: Limbs, b: Limbs) -> (Limbs, bool) {
+        (a.div_rem(b).0, false)
+    }
+
+    fn overflowing_rem(a: Limbs, b: Limbs) -> (Limbs, bool) {
+        (a.div_rem(b).1, false)
+    }
+
+    fn shr(v: Limbs, bits: u32) -> Limbs {
+        v.shr(bits)
+    }
+}
+
+/// Signed, two's complement, interpretation of limbs.
+pub(crate) struct Signed;
+
+impl Signed {
+    /// Returns the magnitude of the value as an unsigned integer, which for
+    /// the minimum value is 2^255.
+    pub(crate) fn abs(v: Limbs) -> Limbs {
+        if v.high_bit() {
+            v.wrapping_neg()
+        } else {
+            v
+        }
+    }
+}
+
+impl Int for Signed {
+    const MIN: Limbs = Limbs([0, 0, 0, 1 << 63]);
+    const MAX: Limbs = Limbs([u64::MAX, u64::MAX, u64::MAX, u64::MAX >> 1]);
+
+    fn is_negative(v: Limbs) -> bool {
+        v.high_bit()
+    }
+
+    fn cmp(a: Limbs, b: Limbs) -> Ordering {
+        match (a.high_bit(), b.high_bit()) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            // Two's complement values of the same sign order as unsigned.
+            _ => a.cmp_unsigned(b),
+        }
+    }
+
+    fn overflowing_add(a: Limbs, b: Limbs) -> (Limbs, bool) {
+        let (r, _) = a.overflowing_add(b);
+        let overflow = a.high_bit() == b.high_bit() && r.high_bit() != a.high_bit();
+        (r, overflow)
+    }
+
+    fn overflowing_sub(a: Limbs, b: Limbs) -> (Limbs, bool) {
+        let (r, _) = a.overflowing_sub(b);
+        let overflow = a.high_bit() != b.high_bit() && r.high_bit() != a.high_bit();
+        (r, overflow)
+    }
+
+    fn overflowing_mul(a: Limbs, b: Limbs) -> (Limbs, bool) {
+        let (magnitude, overflow) = Self::abs(a).overflowing_mul(Self::abs(b));
+        let negative = a.high_bit() != b.high_bit() && !magnitude.is_zero();
+        // The magnitude of a negative product can be one more than that of a
+        // positive product, because the minimum value has no positive
+        // counterpart.
+        let overflow = overflow
+            || if negative {
+                magnitude.cmp_unsigned(Self::MIN) == Ordering::Greater
+            } else {
+                magnitude.high_bit()
+            };
+        let r = if negative {
+            magnitude.wrapping_neg()
+        } else {
+            magnitude
+        };
+        (r, overflow)
+    }
+
+    fn overflowing_div(a: Limbs, b: Limbs) -> (Limbs, bool) {
+        if a == Self::MIN && b == Limbs::ONE.wrapping_neg() {
+            return (Self::MIN, true);
+        }
+        let (q, _) = Self::abs(a).div_rem(Self::abs(b));
+        if a.high_bit() != b.high_bit() {
+            (q.wrapping_neg(), false)
+        } else {
+            (q, false)
+        }
+    }
+
+    fn overflowing_rem(a: Limbs, b: Limbs) -> (Limbs, bool) {
+        if a == Self::MIN && b == Limbs::ONE.wrapping_neg() {
+            return (Limbs::ZERO, true);
+        }
+        // The remainder has the sign of the dividend.
+        let (_, r) = Self::abs(a).div_rem(Self::abs(b));
+        if a.high_bit() {
+            (r.wrapping_neg(), false)
+        } else {
+            (r, false)
+        }
+    }
+
+    fn shr(v: Limbs, bits: u32) -> Limbs {
+        // Arithmetic shift, filling with the sign bit.
+        if v.high_bit() {
+            v.not().shr(bits).not()
+        } else {
+            v.shr(bits)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Int, Limbs, Signed, Unsigned};
+
+    #[test]
+    fn mul_div_against_u128() {
+        let values = [0u128, 1, 2, 3, 7, 255, u64::MAX as u128, 1 << 100];
+        for a in values {
+            for b in values {
+                let (la, lb) = (Limbs::from_u128(a), Limbs::from_u128(b));
+                let (p, o) = Unsigned::overflowing_mul(la, lb);
+                assert_eq!((p.to_u128(), o), (a.checked_mul(b), false));
+                if b != 0 {
+                    // Exercise the long division by scaling both operands
+                    // beyond 128 bits.
+                    let (sa, sb) = (la.shl(100), lb.shl(100));
+                    let (q, _) = Unsigned::overflowing_div(sa, sb);
+                    assert_eq!(q.to_u128(), Some(a / b));
+                    let (r, _) = Unsigned::overflowing_rem(sa, sb);
+                    assert_eq!(r, Limbs::from_u128(a % b).shl(100));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn signed_against_i128() {
+        let values = [
+            0i128,
+            1,
+            -1,
+            2,
+            -2,
+            7,
+            -7,
+            i64::MIN as i128,
+            i64::MAX as i128,
+        ];
+        for a in values {
+            for b in values {
+                let (la, lb) = (Limbs::from_i128(a), Limbs::from_i128(b));
+                assert_eq!(
+                    Signed::overflowing_add(la, lb).0.to_i128(),
+                    a.checked_add(b)
+                );
+                assert_eq!(
+                    Signed::overflowing_sub(la, lb).0.to_i128(),
+                    a.checked_sub(b)
+                );
+                assert_eq!(
+                    Signed::overflowing_mul(la, lb).0.to_i128(),
+                    a.checked_mul(b)
+                );
+                assert_eq!(Signed::cmp(la, lb), a.cmp(&b));
+                if b != 0 {
+                    assert_eq!(
+                        Signed::overflowing_div(la, lb).0.to_i128(),
+                        a.checked_div(b)
+                    );
+                    assert_eq!(
+                        Signed::overflowing_rem(la, lb).0.to_i128(),
+                        a.checked_rem(b)
+                    );
+                }
+                assert_eq!(Signed::shr(la, 3).to_i128(), Some(a >> 3));
+            }
+        }
+    }
+
+    #[test]
+    fn be_bytes_roundtrip() {
+        let mut bytes = [0u8; 32];
+        for (i, b) in bytes.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+        let limbs = Limbs::from_be_bytes(&bytes);
+        assert_eq!(limbs.0[0], 0x18191a1b1c1d1e1f);
+        assert_eq!(limbs.0[3], 0x0001020304050607);
+        assert_eq!(limbs.to_be_bytes(), bytes);
+    }
+}
@@ -1,6 +1,6 @@
 use core::{cmp::Ordering, convert::Infallible, fmt::Debug, iter::FusedIterator};
 
-use crate::unwrap::UnwrapInfallible;
+use crate::{iter::FromIteratorIn, unwrap::UnwrapInfallible};
 
 use super::{
     env::internal::{Env as _, MapObject},
@@ -230,6 +230,19 @@ where
     }
 }
 
+impl<T> FromIteratorIn<T> for Set<T>
+where
+    T: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+    fn from_iter_in<I: IntoIterator<Item = T>>(env: &Env, iter: I) -> Self {
+        let mut set = Set::new(env);
+        for x in iter {
+            set.insert(x);
+        }
+        set
+    }
+}
+
 #[derive(Clone)]
 pub struct SetIter<T>(Set<T>);
 
@@ -593,4 +606,15 @@ mod test {
         let v2: Vec<i64> = Vec::from(s);
         assert_eq!(v2, vec![&env, 1, 2, 3]);
     }
+
+    #[test]
+    fn test_collect_in() {
+        use crate::iter::CollectIn;
+        let env = Env::default();
+
+        let s: Set<u32> = [3, 1, 2, 3, 1].into_iter().collect_in(&env);
+        assert_eq!(s, set![&env, 1, 2, 3]);
+        let s: Set<u32> = s.iter().map(|x| x.unwrap() % 2).collect_in(&env);
+        assert_eq!(s, set![&env, 0, 1]);
+    }
 }
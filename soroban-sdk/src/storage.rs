@@ -7,6 +7,41 @@ use crate::{
     Env, IntoVal, TryFromVal,
 };
 
+mod cached;
+mod map;
+mod set;
+mod vec;
+
+pub use cached::CachedStorage;
+pub use map::StorageMap;
+pub use set::StorageSet;
+pub use vec::{StorageVec, StorageVecIter};
+
+/// Key is implemented by types that can be used as keys in [Storage].
+///
+/// All types that are convertible to [RawVal] are keys that may store values of
+/// any type. Keys defined with [`contractstorage`][crate::contractstorage]
+/// implement [Key] and [KeyFor] for only the value type declared for them.
+pub trait Key {
+    /// Returns the value the key is stored as in the ledger.
+    fn to_key_val(&self, env: &Env) -> RawVal;
+}
+
+/// KeyFor is implemented by keys that may store values of type `V`.
+pub trait KeyFor<V>: Key {}
+
+impl<K> Key for K
+where
+    K: IntoVal<Env, RawVal>,
+{
+    #[inline(always)]
+    fn to_key_val(&self, env: &Env) -> RawVal {
+        self.into_val(env)
+    }
+}
+
+impl<K, V> KeyFor<V> for K where K: IntoVal<Env, RawVal> {}
+
 /// Storage stores and retrieves data for the currently executing contract.
 ///
 /// All data stored can only be queried and modified by the contract that stores
@@ -74,9 +109,9 @@ impl Storage {
     #[inline(always)]
     pub fn has<K>(&self, key: &K) -> bool
     where
-        K: IntoVal<Env, RawVal>,
+        K: Key,
     {
-        self.has_internal(key.into_val(&self.env))
+        self.has_internal(key.to_key_val(&self.env))
     }
 
     /// Returns the value stored for the given key in the currently executing
@@ -87,13 +122,16 @@ impl Storage {
     /// If the value is present, then the returned value will be a result of
     /// converting the internal value representation to `V`, or will panic if
     /// the conversion to `V` fails.
+    ///
+    /// For keys defined with [`contractstorage`][crate::contractstorage] the
+    /// type `V` is the value type declared for the key.
     #[inline(always)]
     pub fn get<K, V>(&self, key: &K) -> Option<V>
     where
-        K: IntoVal<Env, RawVal>,
+        K: KeyFor<V>,
         V: TryFromVal<Env, RawVal>,
     {
-        let key = key.into_val(&self.env);
+        let key = key.to_key_val(&self.env);
         if self.has_internal(key) {
             let rv = self.get_internal(key);
             Some(V::try_from_val(&self.env, &rv).unwrap_optimized())
@@ -110,11 +148,11 @@ impl Storage {
     #[inline(always)]
     pub fn set<K, V>(&self, key: &K, val: &V)
     where
-        K: IntoVal<Env, RawVal>,
+        K: KeyFor<V>,
         V: IntoVal<Env, RawVal>,
     {
         let env = &self.env;
-        internal::Env::put_contract_data(env, key.into_val(env), val.into_val(env))
+        internal::Env::put_contract_data(env, key.to_key_val(env), val.into_val(env))
             .unwrap_infallible();
     }
 
@@ -125,10 +163,10 @@ impl Storage {
     #[inline(always)]
     pub fn remove<K>(&self, key: &K)
     where
-        K: IntoVal<Env, RawVal>,
+        K: Key,
     {
         let env = &self.env;
-        internal::Env::del_contract_data(env, key.into_val(env)).unwrap_infallible();
+        internal::Env::del_contract_data(env, key.to_key_val(env)).unwrap_infallible();
     }
 
     fn has_internal(&self, key: RawVal) -> bool {
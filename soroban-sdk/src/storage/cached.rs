@@ -0,0 +1,245 @@
+use core::{cell::RefCell, fmt::Debug};
+
+#[cfg(any(test, feature = "testutils"))]
+use core::cell::Cell;
+
+use crate::{
+    env::internal::{self, RawVal},
+    unwrap::{UnwrapInfallible, UnwrapOptimized},
+    Env, IntoVal, Map, TryFromVal,
+};
+
+use super::{Key, KeyFor, Storage};
+
+/// CachedStorage wraps [Storage], memoizing reads and buffering writes.
+///
+/// Values read are cached so that reading the same key again does not read
+/// contract storage. Values set and keys removed are kept in the cache, and
+/// are written to contract storage once per key when the cache is flushed,
+/// either explicitly with [flush][CachedStorage::flush] or when the
+/// [CachedStorage] is dropped.
+///
+/// The cache is kept in host maps, so using it still makes host calls, but
+/// those calls do not access contract storage, which costs more.
+///
+/// The cache is not shared with [Storage] or other [CachedStorage]s. Writes
+/// that have not been flushed are not visible to contracts that are called,
+/// or through [Storage], and writes made through [Storage] are not visible
+/// through a [CachedStorage] that has already cached the key.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{storage::CachedStorage, Env, Symbol};
+///
+/// # use soroban_sdk::contractimpl;
+/// #
+/// # pub struct Contract;
+/// #
+/// # #[contractimpl]
+/// # impl Contract {
+/// #     pub fn f(env: Env) {
+/// let storage = CachedStorage::new(&env);
+/// let key = Symbol::short("key");
+/// storage.set(&key, &1);
+/// // Reads the value from the cache.
+/// assert_eq!(storage.get::<_, i32>(&key), Some(1));
+/// // Writes the value to the host.
+/// storage.flush();
+/// assert_eq!(env.storage().get::<_, i32>(&key), Some(1));
+/// #     }
+/// # }
+/// #
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+/// #     let env = Env::default();
+/// #     let contract_id = env.register_contract(None, Contract);
+/// #     ContractClient::new(&env, &contract_id).f();
+/// # }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+pub struct CachedStorage {
+    storage: Storage,
+    // Values known to be stored, including values set that are not flushed.
+    values: RefCell<Map<RawVal, RawVal>>,
+    // Keys known to have no value, including keys removed that are not
+    // flushed.
+    absent: RefCell<Map<RawVal, ()>>,
+    // Keys set or removed that are not flushed.
+    dirty: RefCell<Map<RawVal, ()>>,
+    #[cfg(any(test, feature = "testutils"))]
+    saved_storage_calls: Cell<u32>,
+}
+
+impl Debug for CachedStorage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CachedStorage")
+    }
+}
+
+impl Drop for CachedStorage {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+impl CachedStorage {
+    /// Create a cache over the currently executing contract's storage.
+    #[inline(always)]
+    pub fn new(env: &Env) -> CachedStorage {
+        CachedStorage {
+            storage: Storage::new(env),
+            values: RefCell::new(Map::new(env)),
+            absent: RefCell::new(Map::new(env)),
+            dirty: RefCell::new(Map::new(env)),
+            #[cfg(any(test, feature = "testutils"))]
+            saved_storage_calls: Cell::new(0),
+        }
+    }
+
+    /// Returns if there is a value stored for the given key.
+    ///
+    /// See [Storage::has].
+    #[inline(always)]
+    pub fn has<K>(&self, key: &K) -> bool
+    where
+        K: Key,
+    {
+        let key = key.to_key_val(&self.storage.env);
+        if self.values.borrow().contains_key(key) {
+            self.save_storage_calls(1);
+            true
+        } else if self.absent.borrow().contains_key(key) {
+            self.save_storage_calls(1);
+            false
+        } else if self.storage.has_internal(key) {
+            true
+        } else {
+            self.absent.borrow_mut().set(key, ());
+            false
+        }
+    }
+
+    /// Returns the value stored for the given key, when present.
+    ///
+    /// See [Storage::get].
+    #[inline(always)]
+    pub fn get<K, V>(&self, key: &K) -> Option<V>
+    where
+        K: KeyFor<V>,
+        V: TryFromVal<Env, RawVal>,
+    {
+        let env = &self.storage.env;
+        let key = key.to_key_val(env);
+        let cached = self
+            .values
+            .borrow()
+            .get(key)
+            .map(UnwrapOptimized::unwrap_optimized);
+        let rv = if let Some(rv) = cached {
+            self.save_storage_calls(2);
+            rv
+        } else if self.absent.borrow().contains_key(key) {
+            self.save_storage_calls(1);
+            return None;
+        } else if self.storage.has_internal(key) {
+            let rv = self.storage.get_internal(key);
+            self.values.borrow_mut().set(key, rv);
+            rv
+        } else {
+            self.absent.borrow_mut().set(key, ());
+            return None;
+        };
+        Some(V::try_from_val(env, &rv).unwrap_optimized())
+    }
+
+    /// Sets the value for the given key.
+    ///
+    /// The value is written to the host when the cache is flushed.
+    ///
+    /// See [Storage::set].
+    #[inline(always)]
+    pub fn set<K, V>(&self, key: &K, val: &V)
+    where
+        K: KeyFor<V>,
+        V: IntoVal<Env, RawVal>,
+    {
+        let env = &self.storage.env;
+        let key = key.to_key_val(env);
+        self.values.borrow_mut().set(key, val.into_val(env));
+        self.absent.borrow_mut().remove(key);
+        self.mark_dirty(key);
+    }
+
+    /// Removes the key and the corresponding value.
+    ///
+    /// The key is removed from the host when the cache is flushed.
+    ///
+    /// See [Storage::remove].
+    #[inline(always)]
+    pub fn remove<K>(&self, key: &K)
+    where
+        K: Key,
+    {
+        let key = key.to_key_val(&self.storage.env);
+        self.values.borrow_mut().remove(key);
+        self.absent.borrow_mut().set(key, ());
+        self.mark_dirty(key);
+    }
+
+    /// Writes values set and removes keys removed since the last flush to the
+    /// host.
+    ///
+    /// Values remain cached after flushing.
+    pub fn flush(&self) {
+        let env = &self.storage.env;
+        let dirty = self.dirty.replace(Map::new(env));
+        let values = self.values.borrow();
+        for (key, ()) in dirty.iter_unchecked() {
+            match values.get(key) {
+                Some(val) => {
+                    internal::Env::put_contract_data(env, key, val.unwrap_optimized())
+                        .unwrap_infallible();
+                }
+                None => {
+                    internal::Env::del_contract_data(env, key).unwrap_infallible();
+                }
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn mark_dirty(&self, key: RawVal) {
+        let mut dirty = self.dirty.borrow_mut();
+        if dirty.contains_key(key) {
+            // The previous write of the key will not be made.
+            self.save_storage_calls(1);
+        } else {
+            dirty.set(key, ());
+        }
+    }
+
+    #[inline(always)]
+    fn save_storage_calls(&self, _n: u32) {
+        #[cfg(any(test, feature = "testutils"))]
+        self.saved_storage_calls
+            .set(self.saved_storage_calls.get() + _n);
+    }
+}
+
+#[cfg(any(test, feature = "testutils"))]
+#[cfg_attr(feature = "docs", doc(cfg(feature = "testutils")))]
+impl CachedStorage {
+    /// Returns the number of contract storage calls that were avoided by the
+    /// cache.
+    ///
+    /// Reads served from the cache count the storage reads [Storage] would
+    /// have made for them, and writes that are replaced by another write of
+    /// the same key before flushing count one storage write each. Serving
+    /// reads and buffering writes makes host calls on the cache's maps, that
+    /// are not counted.
+    pub fn saved_storage_calls(&self) -> u32 {
+        self.saved_storage_calls.get()
+    }
+}
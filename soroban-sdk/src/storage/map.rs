@@ -0,0 +1,120 @@
+use core::{fmt::Debug, marker::PhantomData};
+
+use crate::{env::internal::RawVal, Env, IntoVal, TryFromVal};
+
+/// StorageMap is a map stored in the currently executing contract's storage,
+/// where each entry is stored as its own contract data entry.
+///
+/// Entries are stored under keys made up of the map's prefix and the entry's
+/// key. The cost of accessing an entry does not depend on the number of
+/// entries in the map, unlike a [Map][crate::Map] stored under a single key
+/// that is loaded and stored in its entirety on every access.
+///
+/// The prefix is the namespace of the map, and must not be shared with other
+/// storage collections or keys that have the same shape, otherwise their
+/// entries will overlap.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{storage::StorageMap, Env, Symbol};
+///
+/// # use soroban_sdk::contractimpl;
+/// #
+/// # pub struct Contract;
+/// #
+/// # #[contractimpl]
+/// # impl Contract {
+/// #     pub fn f(env: Env) {
+/// let map = StorageMap::<Symbol, u32>::new(&env, Symbol::short("map"));
+/// map.set(&Symbol::short("a"), &1);
+/// assert_eq!(map.contains_key(&Symbol::short("a")), true);
+/// assert_eq!(map.get(&Symbol::short("a")), Some(1));
+/// assert_eq!(map.get(&Symbol::short("b")), None);
+/// #     }
+/// # }
+/// #
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+/// #     let env = Env::default();
+/// #     let contract_id = env.register_contract(None, Contract);
+/// #     ContractClient::new(&env, &contract_id).f();
+/// # }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+pub struct StorageMap<K, V> {
+    env: Env,
+    prefix: RawVal,
+    _k: PhantomData<K>,
+    _v: PhantomData<V>,
+}
+
+impl<K, V> Clone for StorageMap<K, V> {
+    fn clone(&self) -> Self {
+        StorageMap {
+            env: self.env.clone(),
+            prefix: self.prefix,
+            _k: PhantomData,
+            _v: PhantomData,
+        }
+    }
+}
+
+impl<K, V> Debug for StorageMap<K, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "StorageMap({:?})", self.prefix)
+    }
+}
+
+impl<K, V> StorageMap<K, V>
+where
+    K: IntoVal<Env, RawVal>,
+    V: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+    /// Create a map stored under the given prefix.
+    #[inline(always)]
+    pub fn new(env: &Env, prefix: impl IntoVal<Env, RawVal>) -> StorageMap<K, V> {
+        StorageMap {
+            env: env.clone(),
+            prefix: prefix.into_val(env),
+            _k: PhantomData,
+            _v: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    fn key(&self, k: &K) -> (RawVal, RawVal) {
+        (self.prefix, k.into_val(&self.env))
+    }
+
+    /// Returns true if the map contains a value for the key.
+    #[inline(always)]
+    pub fn contains_key(&self, k: &K) -> bool {
+        self.env.storage().has(&self.key(k))
+    }
+
+    /// Returns the value for the key, or `None` if the map does not contain a
+    /// value for the key.
+    #[inline(always)]
+    pub fn get(&self, k: &K) -> Option<V> {
+        self.env.storage().get(&self.key(k))
+    }
+
+    /// Set the value for the key.
+    ///
+    /// If the map already contains a value for the key, the old value is
+    /// replaced.
+    #[inline(always)]
+    pub fn set(&self, k: &K, v: &V) {
+        self.env.storage().set(&self.key(k), v);
+    }
+
+    /// Remove the value for the key.
+    ///
+    /// No-op if the map does not contain a value for the key.
+    #[inline(always)]
+    pub fn remove(&self, k: &K) {
+        self.env.storage().remove(&self.key(k));
+    }
+}
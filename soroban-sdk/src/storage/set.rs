@@ -0,0 +1,109 @@
+use core::{fmt::Debug, marker::PhantomData};
+
+use crate::{env::internal::RawVal, Env, IntoVal};
+
+/// StorageSet is a set stored in the currently executing contract's storage,
+/// where each item is stored as its own contract data entry.
+///
+/// Items are stored under keys made up of the set's prefix and the item. The
+/// cost of accessing an item does not depend on the number of items in the
+/// set, unlike a [Set][crate::Set] stored under a single key that is loaded and
+/// stored in its entirety on every access.
+///
+/// The prefix is the namespace of the set, and must not be shared with other
+/// storage collections or keys that have the same shape, otherwise their
+/// entries will overlap.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{storage::StorageSet, Env, Symbol};
+///
+/// # use soroban_sdk::contractimpl;
+/// #
+/// # pub struct Contract;
+/// #
+/// # #[contractimpl]
+/// # impl Contract {
+/// #     pub fn f(env: Env) {
+/// let set = StorageSet::<u32>::new(&env, Symbol::short("set"));
+/// set.insert(&1);
+/// assert_eq!(set.contains(&1), true);
+/// assert_eq!(set.contains(&2), false);
+/// set.remove(&1);
+/// assert_eq!(set.contains(&1), false);
+/// #     }
+/// # }
+/// #
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+/// #     let env = Env::default();
+/// #     let contract_id = env.register_contract(None, Contract);
+/// #     ContractClient::new(&env, &contract_id).f();
+/// # }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+pub struct StorageSet<T> {
+    env: Env,
+    prefix: RawVal,
+    _t: PhantomData<T>,
+}
+
+impl<T> Clone for StorageSet<T> {
+    fn clone(&self) -> Self {
+        StorageSet {
+            env: self.env.clone(),
+            prefix: self.prefix,
+            _t: PhantomData,
+        }
+    }
+}
+
+impl<T> Debug for StorageSet<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "StorageSet({:?})", self.prefix)
+    }
+}
+
+impl<T> StorageSet<T>
+where
+    T: IntoVal<Env, RawVal>,
+{
+    /// Create a set stored under the given prefix.
+    #[inline(always)]
+    pub fn new(env: &Env, prefix: impl IntoVal<Env, RawVal>) -> StorageSet<T> {
+        StorageSet {
+            env: env.clone(),
+            prefix: prefix.into_val(env),
+            _t: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    fn key(&self, item: &T) -> (RawVal, RawVal) {
+        (self.prefix, item.into_val(&self.env))
+    }
+
+    /// Returns true if the set contains the item.
+    #[inline(always)]
+    pub fn contains(&self, item: &T) -> bool {
+        self.env.storage().has(&self.key(item))
+    }
+
+    /// Insert the item into the set.
+    ///
+    /// No-op if the set already contains the item.
+    #[inline(always)]
+    pub fn insert(&self, item: &T) {
+        self.env.storage().set(&self.key(item), &());
+    }
+
+    /// Remove the item from the set.
+    ///
+    /// No-op if the set does not contain the item.
+    #[inline(always)]
+    pub fn remove(&self, item: &T) {
+        self.env.storage().remove(&self.key(item));
+    }
+}
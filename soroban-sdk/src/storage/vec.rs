@@ -0,0 +1,310 @@
+use core::{fmt::Debug, iter::FusedIterator, marker::PhantomData};
+
+use crate::{env::internal::RawVal, unwrap::UnwrapOptimized, Env, IntoVal, TryFromVal};
+
+/// StorageVec is a vec stored in the currently executing contract's storage,
+/// where each item is stored as its own contract data entry.
+///
+/// Items are stored under keys made up of the vec's prefix and the item's
+/// index, and the length of the vec is stored under a key made up of the
+/// prefix only. The cost of accessing an item does not depend on the length of
+/// the vec, unlike a [Vec][crate::Vec] stored under a single key that is loaded
+/// and stored in its entirety on every access.
+///
+/// The prefix is the namespace of the vec, and must not be shared with other
+/// storage collections or keys that have the same shape, otherwise their
+/// entries will overlap.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{storage::StorageVec, Env, Symbol};
+///
+/// # use soroban_sdk::contractimpl;
+/// #
+/// # pub struct Contract;
+/// #
+/// # #[contractimpl]
+/// # impl Contract {
+/// #     pub fn f(env: Env) {
+/// let vec = StorageVec::<u32>::new(&env, Symbol::short("vec"));
+/// vec.push_back(&1);
+/// vec.push_back(&2);
+/// assert_eq!(vec.len(), 2);
+/// assert_eq!(vec.get(1), Some(2));
+/// assert_eq!(vec.pop_back(), Some(2));
+/// assert_eq!(vec.len(), 1);
+/// #     }
+/// # }
+/// #
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+/// #     let env = Env::default();
+/// #     let contract_id = env.register_contract(None, Contract);
+/// #     ContractClient::new(&env, &contract_id).f();
+/// # }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+pub struct StorageVec<T> {
+    env: Env,
+    prefix: RawVal,
+    _t: PhantomData<T>,
+}
+
+impl<T> Clone for StorageVec<T> {
+    fn clone(&self) -> Self {
+        StorageVec {
+            env: self.env.clone(),
+            prefix: self.prefix,
+            _t: PhantomData,
+        }
+    }
+}
+
+impl<T> Debug for StorageVec<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "StorageVec({:?})", self.prefix)
+    }
+}
+
+impl<T> StorageVec<T>
+where
+    T: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+    /// Create a vec stored under the given prefix.
+    #[inline(always)]
+    pub fn new(env: &Env, prefix: impl IntoVal<Env, RawVal>) -> StorageVec<T> {
+        StorageVec {
+            env: env.clone(),
+            prefix: prefix.into_val(env),
+            _t: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    fn len_key(&self) -> (RawVal,) {
+        (self.prefix,)
+    }
+
+    #[inline(always)]
+    fn item_key(&self, i: u32) -> (RawVal, u32) {
+        (self.prefix, i)
+    }
+
+    #[inline(always)]
+    fn set_len(&self, len: u32) {
+        let storage = self.env.storage();
+        if len == 0 {
+            storage.remove(&self.len_key());
+        } else {
+            storage.set(&self.len_key(), &len);
+        }
+    }
+
+    /// Returns the number of items in the vec.
+    #[inline(always)]
+    pub fn len(&self) -> u32 {
+        self.env.storage().get(&self.len_key()).unwrap_or(0)
+    }
+
+    /// Returns true if the vec is empty and contains no items.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the item at the position or None if out-of-bounds.
+    #[inline(always)]
+    pub fn get(&self, i: u32) -> Option<T> {
+        if i < self.len() {
+            self.env.storage().get(&self.item_key(i))
+        } else {
+            None
+        }
+    }
+
+    /// Sets the item at the position with new value.
+    ///
+    /// ### Panics
+    ///
+    /// If the position is out-of-bounds.
+    #[inline(always)]
+    pub fn set(&self, i: u32, v: &T) {
+        if i >= self.len() {
+            panic!("index out of bounds");
+        }
+        self.env.storage().set(&self.item_key(i), v);
+    }
+
+    /// Appends an item to the back of the vec.
+    #[inline(always)]
+    pub fn push_back(&self, v: &T) {
+        let len = self.len();
+        self.env.storage().set(&self.item_key(len), v);
+        self.set_len(len + 1);
+    }
+
+    /// Removes and returns the last item in the vec.
+    ///
+    /// Returns `None` if the vec is empty.
+    #[inline(always)]
+    pub fn pop_back(&self) -> Option<T> {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        let storage = self.env.storage();
+        let key = self.item_key(len - 1);
+        let last = storage.get(&key);
+        storage.remove(&key);
+        self.set_len(len - 1);
+        last
+    }
+
+    /// Returns the first item in the vec.
+    ///
+    /// Returns `None` if the vec is empty.
+    #[inline(always)]
+    pub fn first(&self) -> Option<T> {
+        self.get(0)
+    }
+
+    /// Returns the last item in the vec.
+    ///
+    /// Returns `None` if the vec is empty.
+    #[inline(always)]
+    pub fn last(&self) -> Option<T> {
+        match self.len() {
+            0 => None,
+            len => self.env.storage().get(&self.item_key(len - 1)),
+        }
+    }
+
+    /// Removes all items from the vec.
+    ///
+    /// Every item is removed individually, so the cost of clearing the vec
+    /// grows with its length.
+    pub fn clear(&self) {
+        let storage = self.env.storage();
+        for i in 0..self.len() {
+            storage.remove(&self.item_key(i));
+        }
+        self.set_len(0);
+    }
+
+    /// Returns an iterator over the items in the vec.
+    ///
+    /// Each item is read from storage when the iterator reaches it.
+    #[inline(always)]
+    pub fn iter(&self) -> StorageVecIter<T> {
+        StorageVecIter {
+            vec: self.clone(),
+            start: 0,
+            end: self.len(),
+        }
+    }
+}
+
+impl<T> IntoIterator for StorageVec<T>
+where
+    T: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+    type Item = T;
+    type IntoIter = StorageVecIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let end = self.len();
+        StorageVecIter {
+            vec: self,
+            start: 0,
+            end,
+        }
+    }
+}
+
+/// StorageVecIter is an iterator over the items of a [StorageVec], returned by
+/// [StorageVec::iter] and by converting a [StorageVec] into an iterator.
+///
+/// The iterator covers the indexes of the items in the vec when it was
+/// created, and reads each item from storage when it reaches it.
+///
+/// ### Panics
+///
+/// If the vec is shortened before the iterator reaches an item beyond its new
+/// length.
+pub struct StorageVecIter<T> {
+    vec: StorageVec<T>,
+    start: u32,
+    end: u32,
+}
+
+impl<T> Clone for StorageVecIter<T> {
+    fn clone(&self) -> Self {
+        StorageVecIter {
+            vec: self.vec.clone(),
+            start: self.start,
+            end: self.end,
+        }
+    }
+}
+
+impl<T> StorageVecIter<T>
+where
+    T: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+    fn get(&self, i: u32) -> T {
+        self.vec
+            .env
+            .storage()
+            .get(&self.vec.item_key(i))
+            .unwrap_optimized()
+    }
+}
+
+impl<T> Iterator for StorageVecIter<T>
+where
+    T: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start < self.end {
+            let item = self.get(self.start);
+            self.start += 1;
+            Some(item)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.end - self.start) as usize;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for StorageVecIter<T>
+where
+    T: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start < self.end {
+            self.end -= 1;
+            Some(self.get(self.end))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> FusedIterator for StorageVecIter<T> where T: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal> {}
+
+impl<T> ExactSizeIterator for StorageVecIter<T>
+where
+    T: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+    fn len(&self) -> usize {
+        (self.end - self.start) as usize
+    }
+}
@@ -1,10 +1,12 @@
 #![cfg(test)]
 
 mod address;
+mod auth_tree;
 mod budget;
 mod contract_add_i32;
 mod contract_assert;
 mod contract_call_stack;
+mod contract_constructor;
 mod contract_docs;
 mod contract_invoke;
 mod contract_overlapping_type_fn_names;
@@ -13,10 +15,22 @@ mod contract_store;
 mod contract_udt_enum;
 mod contract_udt_struct;
 mod contract_udt_struct_tuple;
+mod contractevent;
 mod contractfile_with_sha256;
 mod contractimport;
 mod contractimport_with_error;
 mod contractimport_with_sha256;
+mod contractstorage;
+mod crypto;
+mod crypto_merkle;
+mod deployer;
 mod env;
+mod events;
+mod ledger;
+mod math;
+mod num;
+mod sign_auth;
+mod storage_cached;
+mod storage_collections;
 mod token_client;
 mod token_spec;
@@ -1,6 +1,6 @@
 use crate::{
     xdr::{AccountId, Hash, PublicKey, ScAddress, Uint256},
-    Address, BytesN, Env, TryFromVal, TryIntoVal,
+    Address, AddressKind, BytesN, Env, TryFromVal, TryIntoVal,
 };
 
 #[test]
@@ -32,3 +32,80 @@ fn test_contract_address_conversions() {
     let contract_address_rt: Address = scaddress.try_into_val(&env).unwrap();
     assert_eq!(contract_address_rt, contract_address);
 }
+
+#[test]
+fn test_address_kind() {
+    let env = Env::default();
+
+    let account = Address::try_from_val(
+        &env,
+        &ScAddress::Account(AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(
+            [222u8; 32],
+        )))),
+    )
+    .unwrap();
+    assert_eq!(account.kind(), AddressKind::Account);
+    assert_eq!(account.try_contract_id(), None);
+
+    let contract = Address::from_contract_id(&BytesN::from_array(&env, &[111u8; 32]));
+    assert_eq!(contract.kind(), AddressKind::Contract);
+    assert_eq!(
+        contract.try_contract_id(),
+        Some(BytesN::from_array(&env, &[111u8; 32]))
+    );
+}
+
+#[test]
+fn test_account_address_strkey() {
+    let env = Env::default();
+    let strkey = "GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ";
+
+    let address = Address::from_strkey(&env, strkey);
+    assert_eq!(address.kind(), AddressKind::Account);
+    let scaddress: ScAddress = (&address).try_into().unwrap();
+    let ScAddress::Account(AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(key)))) = scaddress
+    else {
+        panic!("address is not an account");
+    };
+    assert_eq!(
+        key,
+        [
+            0x3f, 0x0c, 0x34, 0xbf, 0x93, 0xad, 0x0d, 0x99, 0x71, 0xd0, 0x4c, 0xcc, 0x90, 0xf7,
+            0x05, 0x51, 0x1c, 0x83, 0x8a, 0xad, 0x97, 0x34, 0xa4, 0xa2, 0xfb, 0x0d, 0x7a, 0x03,
+            0xfc, 0x7f, 0xe8, 0x9a,
+        ]
+    );
+
+    assert_eq!(address.to_strkey(), strkey);
+    assert_eq!(std::format!("{address}"), strkey);
+    assert_eq!(
+        std::format!("{address:?}"),
+        std::format!("AccountId({strkey})")
+    );
+}
+
+#[test]
+fn test_contract_address_strkey() {
+    let env = Env::default();
+    let contract = Address::from_contract_id(&BytesN::from_array(&env, &[111u8; 32]));
+
+    let strkey = contract.to_strkey();
+    assert!(strkey.starts_with('C'));
+    assert_eq!(Address::from_strkey(&env, &strkey), contract);
+    assert_eq!(std::format!("{contract}"), strkey);
+    assert_eq!(
+        std::format!("{contract:?}"),
+        std::format!("Contract({strkey})")
+    );
+}
+
+#[test]
+#[should_panic(expected = "invalid address strkey")]
+fn test_address_from_invalid_strkey() {
+    let env = Env::default();
+    // Secret seeds are valid strkeys, but not addresses.
+    Address::from_strkey(
+        &env,
+        "SBU2RRGLXH3E5CQHTD3ODLDF2BWDCYUSSBLLZ5GNW7JXHDIYKXZWHOKR",
+    );
+}
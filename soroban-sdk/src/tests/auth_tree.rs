@@ -0,0 +1,113 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contractimpl,
+    testutils::{Address as _, MockAuthInvoke},
+    Address, Env, IntoVal,
+};
+
+pub struct Token;
+
+#[contractimpl]
+impl Token {
+    pub fn transfer(_env: Env, from: Address, _to: Address, _amount: i128) {
+        from.require_auth();
+    }
+}
+
+pub struct Router;
+
+#[contractimpl]
+impl Router {
+    pub fn swap(env: Env, token: Address, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        TokenClient::new(&env, &token).transfer(&from, &to, &amount);
+    }
+}
+
+#[test]
+fn test_auths_tree() {
+    let e = Env::default();
+    let token_id = e.register_contract(None, Token);
+    let router_id = e.register_contract(None, Router);
+    let client = RouterClient::new(&e, &router_id);
+    let from = Address::random(&e);
+    let to = Address::random(&e);
+
+    e.mock_all_auths();
+    client.swap(&token_id, &from, &to, &10);
+
+    let auths = e.auths_tree();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, from);
+    let swap = &auths[0].1;
+    assert_eq!(swap.contract, router_id);
+    assert_eq!(swap.fn_name, soroban_sdk::Symbol::short("swap"));
+    assert_eq!(swap.sub_invocations.len(), 1);
+    assert_eq!(swap.sub_invocations[0].contract, token_id);
+    swap.sub_invocations[0].assert_matches(&MockAuthInvoke {
+        contract: &token_id,
+        fn_name: "transfer",
+        args: (&from, &to, 10_i128).into_val(&e),
+        sub_invokes: &[],
+    });
+
+    e.assert_auths_tree(&[(
+        &from,
+        &MockAuthInvoke {
+            contract: &router_id,
+            fn_name: "swap",
+            args: (&token_id, &from, &to, 10_i128).into_val(&e),
+            sub_invokes: &[MockAuthInvoke {
+                contract: &token_id,
+                fn_name: "transfer",
+                args: (&from, &to, 10_i128).into_val(&e),
+                sub_invokes: &[],
+            }],
+        },
+    )]);
+}
+
+#[test]
+#[should_panic(expected = "authorizations do not match (- expected, + actual)")]
+fn test_assert_auths_tree_mismatch() {
+    let e = Env::default();
+    let token_id = e.register_contract(None, Token);
+    let router_id = e.register_contract(None, Router);
+    let client = RouterClient::new(&e, &router_id);
+    let from = Address::random(&e);
+    let to = Address::random(&e);
+
+    e.mock_all_auths();
+    client.swap(&token_id, &from, &to, &10);
+
+    // The transfer is expected at the top level, rather than within the swap.
+    e.assert_auths_tree(&[(
+        &from,
+        &MockAuthInvoke {
+            contract: &token_id,
+            fn_name: "transfer",
+            args: (&from, &to, 10_i128).into_val(&e),
+            sub_invokes: &[],
+        },
+    )]);
+}
+
+#[test]
+#[should_panic(expected = "authorized invocations do not match")]
+fn test_assert_matches_mismatch() {
+    let e = Env::default();
+    let token_id = e.register_contract(None, Token);
+    let client = TokenClient::new(&e, &token_id);
+    let from = Address::random(&e);
+    let to = Address::random(&e);
+
+    e.mock_all_auths();
+    client.transfer(&from, &to, &10);
+
+    e.auths_tree()[0].1.assert_matches(&MockAuthInvoke {
+        contract: &token_id,
+        fn_name: "transfer",
+        args: (&from, &to, 11_i128).into_val(&e),
+        sub_invokes: &[],
+    });
+}
@@ -1,5 +1,5 @@
 use crate as soroban_sdk;
-use soroban_sdk::{contractimpl, map, xdr::ContractCostType, Env, Map};
+use soroban_sdk::{contractimpl, map, xdr::ContractCostType, Env, Map, Vec};
 
 pub struct Contract;
 
@@ -30,3 +30,51 @@ fn test_budget() {
     assert_eq!(e.budget().tracker(ContractCostType::MapEntry), (5, None));
     assert_eq!(b, map![&e, (1, 10), (2, 20)]);
 }
+
+/// Returns the CPU instruction cost of the function on a vec of `len` integers
+/// in descending order.
+fn vec_cost(e: &Env, len: u32, f: impl FnOnce(&mut Vec<u32>)) -> u64 {
+    let mut v = Vec::new(e);
+    for i in (0..len).rev() {
+        v.push_back(i);
+    }
+    e.budget().reset_default();
+    f(&mut v);
+    e.budget().print();
+    e.budget().cpu_instruction_cost()
+}
+
+#[test]
+fn test_budget_vec() {
+    let e = Env::default();
+
+    // Each operation fits in the default budget.
+    let sort = vec_cost(&e, 100, |v| v.sort());
+    let sort_by_key = vec_cost(&e, 100, |v| v.sort_by_key(|x| x % 10));
+    let dedup = vec_cost(&e, 100, |v| v.dedup());
+    let retain = vec_cost(&e, 100, |v| v.retain(|x| x % 2 == 0));
+    let reverse = vec_cost(&e, 100, |v| v.reverse());
+    let truncate = vec_cost(&e, 100, |v| v.truncate(50));
+    let split_off = vec_cost(&e, 100, |v| {
+        let _ = v.split_off(50);
+    });
+
+    // Operations on slices of the vec cost less than those that visit each
+    // element, and sorting, which compares each element many times, costs the
+    // most.
+    assert!(truncate < reverse);
+    assert!(split_off < reverse);
+    assert!(reverse < sort);
+    assert!(retain < sort);
+    assert!(dedup < sort);
+    assert!(sort < sort_by_key);
+
+    // The cost of sorting grows as n log n, so doubling the length of a vec
+    // roughly doubles the cost, where a quadratic sort would quadruple it.
+    let sort = vec_cost(&e, 256, |v| v.sort());
+    let sort_half = vec_cost(&e, 128, |v| v.sort());
+    let sort_by_key = vec_cost(&e, 256, |v| v.sort_by_key(|x| x % 10));
+    let sort_by_key_half = vec_cost(&e, 128, |v| v.sort_by_key(|x| x % 10));
+    assert!(sort < 3 * sort_half);
+    assert!(sort_by_key < 3 * sort_by_key_half);
+}
@@ -0,0 +1,134 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contractimpl,
+    testutils::{Address as _, BytesN as _},
+    Address, BytesN, Env, IntoVal, RawVal, Symbol, Vec,
+};
+use stellar_xdr::{
+    ReadXdr, ScMetaEntry, ScMetaV0, ScSpecEntry, ScSpecFunctionInputV0, ScSpecFunctionV0,
+    ScSpecTypeDef,
+};
+
+const ADMIN: Symbol = Symbol::short("ADMIN");
+
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().set(&ADMIN, &admin);
+    }
+
+    pub fn admin(env: Env) -> Address {
+        env.storage().get(&ADMIN).unwrap()
+    }
+}
+
+pub struct Deployer;
+
+#[contractimpl]
+impl Deployer {
+    pub fn construct(env: Env, contract_id: Address, salt: BytesN<32>, admin: Address) {
+        env.invoke_contract::<()>(
+            &contract_id,
+            &Symbol::new(&env, "__constructor"),
+            (salt, admin).into_val(&env),
+        );
+    }
+}
+
+#[test]
+fn test_functional() {
+    let e = Env::default();
+    let admin = Address::random(&e);
+    let contract_id = e.register_contract_with_constructor(None, Contract, (admin.clone(),));
+    let client = ContractClient::new(&e, &contract_id);
+    assert_eq!(client.admin(), admin);
+
+    // The constructor rejects invocations by anyone but the deployer.
+    let res = e.try_invoke_contract::<(), soroban_sdk::Error>(
+        &contract_id,
+        &Symbol::new(&e, "__constructor"),
+        (BytesN::<32>::random(&e), Address::random(&e)).into_val(&e),
+    );
+    assert!(res.is_err());
+    assert_eq!(client.admin(), admin);
+}
+
+#[test]
+fn test_register_with_contract_id() {
+    let e = Env::default();
+    let admin = Address::random(&e);
+    let contract_id = Address::random(&e);
+    let registered = e.register_contract_with_constructor(&contract_id, Contract, (admin.clone(),));
+    assert_eq!(registered, contract_id);
+    assert_eq!(ContractClient::new(&e, &contract_id).admin(), admin);
+}
+
+#[test]
+fn test_constructor_runs_once() {
+    let e = Env::default();
+    let deployer_id = e.register_contract(None, Deployer);
+    let deployer = DeployerClient::new(&e, &deployer_id);
+    let salt = BytesN::random(&e);
+    let contract_id = e.deployer().with_other_contract(&deployer_id, &salt).id();
+    e.register_contract(&contract_id, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+
+    let admin = Address::random(&e);
+    deployer.construct(&contract_id, &salt, &admin);
+    assert_eq!(client.admin(), admin);
+
+    // The deployer cannot invoke the constructor again.
+    let other = Address::random(&e);
+    assert!(deployer.try_construct(&contract_id, &salt, &other).is_err());
+    assert_eq!(client.admin(), admin);
+}
+
+#[test]
+fn test_constructor_runs_once_when_registered() {
+    let e = Env::default();
+    let admin = Address::random(&e);
+    let contract_id = e.register_contract_with_constructor(None, Contract, (admin.clone(),));
+
+    // The contract the environment invokes constructors from cannot invoke the
+    // constructor again.
+    let test_deployer =
+        Address::from_contract_id(&BytesN::from_array(&e, &crate::deploy::TEST_DEPLOYER_ID));
+    let args: Vec<RawVal> = (BytesN::from_array(&e, &[0; 32]), Address::random(&e)).into_val(&e);
+    let res = e.try_invoke_contract::<(), soroban_sdk::Error>(
+        &test_deployer,
+        &Symbol::short("deploy"),
+        (contract_id.clone(), args).into_val(&e),
+    );
+    assert!(res.is_err());
+    assert_eq!(ContractClient::new(&e, &contract_id).admin(), admin);
+}
+
+#[test]
+fn test_spec() {
+    let entries = ScSpecEntry::from_xdr(__SPEC_XDR_FN___CONSTRUCTOR).unwrap();
+    let expect = ScSpecEntry::FunctionV0(ScSpecFunctionV0 {
+        doc: "".try_into().unwrap(),
+        name: "__constructor".try_into().unwrap(),
+        inputs: vec![ScSpecFunctionInputV0 {
+            doc: "".try_into().unwrap(),
+            name: "admin".try_into().unwrap(),
+            type_: ScSpecTypeDef::Address,
+        }]
+        .try_into()
+        .unwrap(),
+        outputs: vec![].try_into().unwrap(),
+    });
+    assert_eq!(entries, expect);
+}
+
+#[test]
+fn test_meta() {
+    let entry = ScMetaEntry::from_xdr(__CONTRACT_META_CONSTRUCTOR).unwrap();
+    let expect = ScMetaEntry::ScMetaV0(ScMetaV0 {
+        key: "constructor".try_into().unwrap(),
+        val: "__constructor".try_into().unwrap(),
+    });
+    assert_eq!(entry, expect);
+}
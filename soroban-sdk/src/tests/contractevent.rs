@@ -0,0 +1,194 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contractevent, contractimpl,
+    events::Event,
+    map,
+    testutils::{Address as _, ContractEvent, Events},
+    vec, Address, Env, IntoVal, Map, RawVal, Symbol,
+};
+use stellar_xdr::{ReadXdr, ScSpecEntry, ScSpecTypeDef, ScSpecUdtStructFieldV0, ScSpecUdtStructV0};
+
+/// A transfer of an amount.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Transfer {
+    /// The sender.
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub amount: i128,
+    pub memo: u64,
+}
+
+#[contractevent(name = "paused")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseEvent {
+    #[topic]
+    pub paused: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HTTPRequestSent {
+    pub status: u32,
+}
+
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        Transfer {
+            from,
+            to,
+            amount,
+            memo: 1,
+        }
+        .publish(&env);
+    }
+
+    pub fn pause(env: Env) {
+        PauseEvent { paused: true }.publish(&env);
+        env.events().publish((Symbol::short("other"),), 1u32);
+    }
+}
+
+#[test]
+fn test_publish() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+
+    let from = Address::random(&e);
+    let to = Address::random(&e);
+    client.transfer(&from, &to, &10);
+    client.pause();
+
+    let data: RawVal = map![
+        &e,
+        (Symbol::short("amount"), 10i128.into_val(&e)),
+        (Symbol::short("memo"), 1u64.into_val(&e)),
+    ]
+    .into_val(&e);
+    let paused_data: RawVal = Map::<Symbol, RawVal>::new(&e).into_val(&e);
+    assert_eq!(
+        e.events().all(),
+        vec![
+            &e,
+            (
+                contract_id.clone(),
+                (Symbol::short("transfer"), from.clone(), to.clone()).into_val(&e),
+                data,
+            ),
+            (
+                contract_id.clone(),
+                (Symbol::short("paused"), true).into_val(&e),
+                paused_data,
+            ),
+            (
+                contract_id.clone(),
+                (Symbol::short("other"),).into_val(&e),
+                1u32.into_val(&e),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_decode() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+
+    let from = Address::random(&e);
+    let to = Address::random(&e);
+    client.transfer(&from, &to, &10);
+    client.pause();
+    client.transfer(&to, &from, &5);
+
+    let transfers = e.events().all_of::<Transfer>();
+    assert_eq!(
+        transfers,
+        [
+            (
+                contract_id.clone(),
+                Transfer {
+                    from: from.clone(),
+                    to: to.clone(),
+                    amount: 10,
+                    memo: 1,
+                }
+            ),
+            (
+                contract_id.clone(),
+                Transfer {
+                    from: to.clone(),
+                    to: from.clone(),
+                    amount: 5,
+                    memo: 1,
+                }
+            ),
+        ]
+    );
+    assert_eq!(
+        e.events().all_of::<PauseEvent>(),
+        [(contract_id.clone(), PauseEvent { paused: true })]
+    );
+
+    // Events with other names, topics, or data do not decode.
+    let (_, topics, data) = e.events().all().get_unchecked(1).unwrap();
+    assert!(Transfer::try_from_event(&e, &topics, &data).is_err());
+    assert_eq!(
+        PauseEvent::try_from_event(&e, &topics, &data).unwrap(),
+        PauseEvent { paused: true }
+    );
+    let topics = vec![&e, Symbol::short("paused").into_val(&e)];
+    assert!(PauseEvent::try_from_event(&e, &topics, &data).is_err());
+}
+
+#[test]
+fn test_name_of_acronym() {
+    let e = Env::default();
+    let event = HTTPRequestSent { status: 200 };
+    let topics = event.topics(&e);
+    assert_eq!(
+        topics,
+        vec![&e, Symbol::new(&e, "http_request_sent").into_val(&e)]
+    );
+}
+
+#[test]
+fn test_spec() {
+    let entry = ScSpecEntry::from_xdr(Transfer::spec_xdr()).unwrap();
+    let expect = ScSpecEntry::UdtStructV0(ScSpecUdtStructV0 {
+        doc: "A transfer of an amount.".try_into().unwrap(),
+        lib: "".try_into().unwrap(),
+        name: "Transfer".try_into().unwrap(),
+        fields: [
+            ScSpecUdtStructFieldV0 {
+                doc: "topic: The sender.".try_into().unwrap(),
+                name: "from".try_into().unwrap(),
+                type_: ScSpecTypeDef::Address,
+            },
+            ScSpecUdtStructFieldV0 {
+                doc: "topic".try_into().unwrap(),
+                name: "to".try_into().unwrap(),
+                type_: ScSpecTypeDef::Address,
+            },
+            ScSpecUdtStructFieldV0 {
+                doc: "data".try_into().unwrap(),
+                name: "amount".try_into().unwrap(),
+                type_: ScSpecTypeDef::I128,
+            },
+            ScSpecUdtStructFieldV0 {
+                doc: "data".try_into().unwrap(),
+                name: "memo".try_into().unwrap(),
+                type_: ScSpecTypeDef::U64,
+            },
+        ]
+        .try_into()
+        .unwrap(),
+    });
+    assert_eq!(entry, expect);
+}
@@ -0,0 +1,91 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contractimpl, contractstorage, contracttype, testutils::Address as _, Address, Env,
+};
+
+#[contractstorage]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    #[value(u32)]
+    Counter,
+    #[value(i128)]
+    Balance(Address),
+    #[value(bool)]
+    Approved(Address, Address),
+}
+
+#[contracttype]
+pub enum UntypedDataKey {
+    Counter,
+    Balance(Address),
+    Approved(Address, Address),
+}
+
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn incr(env: Env) -> u32 {
+        let count = env.storage().get(&DataKey::Counter).unwrap_or(0) + 1;
+        env.storage().set(&DataKey::Counter, &count);
+        count
+    }
+}
+
+#[test]
+fn test_typed_keys() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+
+    assert_eq!(client.incr(), 1);
+    assert_eq!(client.incr(), 2);
+
+    let a = Address::random(&e);
+    let b = Address::random(&e);
+    e.as_contract(&contract_id, || {
+        let storage = e.storage();
+        assert_eq!(storage.get(&DataKey::Counter), Some(2));
+
+        assert!(!storage.has(&DataKey::Balance(a.clone())));
+        storage.set(&DataKey::Balance(a.clone()), &100);
+        assert!(storage.has(&DataKey::Balance(a.clone())));
+        assert_eq!(storage.get(&DataKey::Balance(a.clone())), Some(100));
+        assert_eq!(storage.get(&DataKey::Balance(b.clone())), None);
+
+        storage.set(&DataKey::Approved(a.clone(), b.clone()), &true);
+        assert_eq!(
+            storage.get(&DataKey::Approved(a.clone(), b.clone())),
+            Some(true)
+        );
+        assert_eq!(storage.get(&DataKey::Approved(b.clone(), a.clone())), None);
+
+        storage.remove(&DataKey::Balance(a.clone()));
+        assert!(!storage.has(&DataKey::Balance(a.clone())));
+    });
+}
+
+#[test]
+fn test_typed_keys_compatible_with_contracttype() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+
+    let a = Address::random(&e);
+    let b = Address::random(&e);
+    e.as_contract(&contract_id, || {
+        let storage = e.storage();
+        storage.set(&DataKey::Counter, &5);
+        storage.set(&DataKey::Balance(a.clone()), &100);
+        storage.set(&UntypedDataKey::Approved(a.clone(), b.clone()), &true);
+
+        assert_eq!(storage.get::<_, u32>(&UntypedDataKey::Counter), Some(5));
+        assert_eq!(
+            storage.get::<_, i128>(&UntypedDataKey::Balance(a.clone())),
+            Some(100)
+        );
+        assert_eq!(
+            storage.get(&DataKey::Approved(a.clone(), b.clone())),
+            Some(true)
+        );
+    });
+}
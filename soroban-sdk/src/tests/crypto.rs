@@ -0,0 +1,144 @@
+use crate as soroban_sdk;
+use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+use soroban_sdk::{contractimpl, testutils::ed25519, vec, xdr, Bytes, BytesN, Env};
+
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn verify(env: Env, public_key: BytesN<32>, message: Bytes, signature: BytesN<64>) {
+        env.crypto()
+            .ed25519_verify(&public_key, &message, &signature)
+    }
+
+    pub fn verify_batch(env: Env, entries: soroban_sdk::Vec<(BytesN<32>, Bytes, BytesN<64>)>) {
+        env.crypto().ed25519_verify_batch(&entries)
+    }
+}
+
+fn keypair(seed: u8) -> Keypair {
+    let secret = SecretKey::from_bytes(&[seed; 32]).unwrap();
+    let public = PublicKey::from(&secret);
+    Keypair { secret, public }
+}
+
+fn signed(e: &Env, kp: &Keypair, message: &[u8]) -> (BytesN<32>, Bytes, BytesN<64>) {
+    (
+        BytesN::from_array(e, &kp.public.to_bytes()),
+        Bytes::from_slice(e, message),
+        BytesN::from_array(e, &kp.sign(message).to_bytes()),
+    )
+}
+
+#[test]
+fn test_ed25519_verify() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+
+    let (public_key, message, signature) = signed(&e, &keypair(1), b"hello");
+    client.verify(&public_key, &message, &signature);
+}
+
+#[test]
+fn test_ed25519_verify_invalid_signature() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+
+    let (public_key, _, signature) = signed(&e, &keypair(1), b"hello");
+    let message = Bytes::from_slice(&e, b"other");
+    assert!(client
+        .try_verify(&public_key, &message, &signature)
+        .is_err());
+
+    // A signature by another key is invalid.
+    let (other_public_key, _, _) = signed(&e, &keypair(2), b"hello");
+    let message = Bytes::from_slice(&e, b"hello");
+    assert!(client
+        .try_verify(&other_public_key, &message, &signature)
+        .is_err());
+}
+
+#[test]
+fn test_ed25519_verify_malformed_public_key() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+
+    let (_, message, signature) = signed(&e, &keypair(1), b"hello");
+    // The y-coordinate 2 is not on the curve.
+    let mut malformed = [0; 32];
+    malformed[0] = 2;
+    let public_key = BytesN::from_array(&e, &malformed);
+    assert!(client
+        .try_verify(&public_key, &message, &signature)
+        .is_err());
+}
+
+#[test]
+fn test_ed25519_verify_batch() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+
+    let entries = vec![
+        &e,
+        signed(&e, &keypair(1), b"a"),
+        signed(&e, &keypair(2), b"b"),
+        signed(&e, &keypair(3), b"c"),
+    ];
+    client.verify_batch(&entries);
+
+    let (public_key, _, signature) = signed(&e, &keypair(2), b"b");
+    let mut invalid = entries.clone();
+    invalid.set(1, (public_key, Bytes::from_slice(&e, b"x"), signature));
+    assert!(client.try_verify_batch(&invalid).is_err());
+}
+
+#[test]
+fn test_testutils_ed25519_keypair() {
+    use soroban_sdk::testutils::ed25519::{Sign, Verify as _};
+
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+
+    let kp = ed25519::Keypair::generate(&e);
+    let message = Bytes::from_slice(&e, b"hello");
+    let signature = kp.sign_bytes(&message);
+    client.verify(&kp.public_key(), &message, &signature);
+    assert!(client
+        .try_verify(&kp.public_key(), &Bytes::from_slice(&e, b"x"), &signature)
+        .is_err());
+
+    // Signatures of values are over their XDR.
+    let signature = Sign::sign(&kp, 128i64).unwrap();
+    assert!(kp.verify(128i64, &signature).is_ok());
+    assert!(kp.verify(129i64, &signature).is_err());
+
+    // Keypairs from the same seed are the same.
+    let kp = ed25519::Keypair::from_seed(&e, &[1; 32]);
+    assert_eq!(
+        kp.public_key(),
+        BytesN::from_array(&e, &keypair(1).public.to_bytes())
+    );
+    assert_eq!(
+        kp.public_key(),
+        ed25519::Keypair::from_seed(&e, &[1; 32]).public_key()
+    );
+    assert_ne!(kp.public_key(), ed25519::Keypair::generate(&e).public_key());
+}
+
+#[test]
+fn test_testutils_ed25519_keypair_address() {
+    let e = Env::default();
+    let kp = ed25519::Keypair::from_seed(&e, &[1; 32]);
+    let address: xdr::ScAddress = kp.address().try_into().unwrap();
+    assert_eq!(
+        address,
+        xdr::ScAddress::Account(xdr::AccountId(xdr::PublicKey::PublicKeyTypeEd25519(
+            xdr::Uint256(keypair(1).public.to_bytes())
+        )))
+    );
+}
@@ -0,0 +1,171 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contractimpl,
+    crypto::merkle::{self, Sha256},
+    testutils::MerkleTree,
+    vec, Bytes, BytesN, Env, Vec,
+};
+
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn root(env: Env, leaves: Vec<BytesN<32>>) -> BytesN<32> {
+        merkle::root::<Sha256>(&env, &leaves)
+    }
+
+    pub fn verify(
+        env: Env,
+        root: BytesN<32>,
+        leaf: BytesN<32>,
+        index: u32,
+        count: u32,
+        proof: Vec<BytesN<32>>,
+    ) -> bool {
+        merkle::verify::<Sha256>(&env, &root, &leaf, index, count, &proof)
+    }
+
+    pub fn verify_sorted(
+        env: Env,
+        root: BytesN<32>,
+        leaf: BytesN<32>,
+        proof: Vec<BytesN<32>>,
+    ) -> bool {
+        merkle::verify_sorted::<Sha256>(&env, &root, &leaf, &proof)
+    }
+}
+
+fn leaves(e: &Env, n: u8) -> std::vec::Vec<BytesN<32>> {
+    (0..n)
+        .map(|i| e.crypto().sha256(&Bytes::from_array(e, &[i])))
+        .collect()
+}
+
+#[test]
+fn test_single_leaf() {
+    let e = Env::default();
+    let leaves = leaves(&e, 1);
+    let tree = MerkleTree::<Sha256>::new(&e, &leaves);
+    assert_eq!(tree.root(), leaves[0]);
+    assert_eq!(tree.proof(0), vec![&e]);
+    assert!(merkle::verify::<Sha256>(
+        &e,
+        &tree.root(),
+        &leaves[0],
+        0,
+        1,
+        &tree.proof(0)
+    ));
+}
+
+#[test]
+fn test_root_of_two_leaves() {
+    let e = Env::default();
+    let leaves = leaves(&e, 2);
+    let mut data = Bytes::from(&leaves[0]);
+    data.append(&Bytes::from(&leaves[1]));
+    assert_eq!(
+        merkle::root::<Sha256>(&e, &Vec::from_slice(&e, &leaves)),
+        e.crypto().sha256(&data)
+    );
+}
+
+#[test]
+fn test_verify_positional() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+
+    for n in 1..=7 {
+        let leaves = leaves(&e, n);
+        let tree = MerkleTree::<Sha256>::new(&e, &leaves);
+        let root = tree.root();
+        assert_eq!(client.root(&tree.leaves()), root);
+
+        let count = u32::from(n);
+        for (i, leaf) in leaves.iter().enumerate() {
+            let i = i as u32;
+            let proof = tree.proof(i);
+            assert!(client.verify(&root, leaf, &i, &count, &proof));
+            // The proof does not verify the leaf at any other index.
+            for j in (0..count + 2).filter(|j| *j != i) {
+                assert!(!client.verify(&root, leaf, &j, &count, &proof));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_verify_positional_invalid() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+
+    let leaves = leaves(&e, 5);
+    let tree = MerkleTree::<Sha256>::new(&e, &leaves);
+    let root = tree.root();
+    let proof = tree.proof(1);
+
+    assert!(client.verify(&root, &leaves[1], &1, &5, &proof));
+    // Another leaf.
+    let other = e.crypto().sha256(&Bytes::from_array(&e, &[9]));
+    assert!(!client.verify(&root, &other, &1, &5, &proof));
+    // A truncated proof.
+    assert!(!client.verify(&root, &leaves[1], &1, &5, &proof.slice(..2)));
+    // A proof with an extra node.
+    let mut extended = proof.clone();
+    extended.push_back(other);
+    assert!(!client.verify(&root, &leaves[1], &1, &5, &extended));
+    // An index beyond the leaves of the tree.
+    assert!(!client.verify(&root, &leaves[1], &(1 + 8), &5, &proof));
+    // Another number of leaves.
+    assert!(!client.verify(&root, &leaves[1], &1, &8, &proof));
+}
+
+#[test]
+fn test_verify_positional_rejects_duplicate_index() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+
+    // With leaves [a, b, c], pairing c with a copy of itself would give
+    // [a, b, c, c] the same root, and prove c at both index 2 and index 3.
+    let leaves = leaves(&e, 3);
+    let (a, b, c) = (&leaves[0], &leaves[1], &leaves[2]);
+    let tree = MerkleTree::<Sha256>::new(&e, &leaves);
+    let root = tree.root();
+    let ab = merkle::hash_pair::<Sha256>(&e, a, b);
+    assert_eq!(root, merkle::hash_pair::<Sha256>(&e, &ab, c));
+    assert_eq!(tree.proof(2), vec![&e, ab.clone()]);
+    assert!(client.verify(&root, c, &2, &3, &tree.proof(2)));
+
+    let duplicated = vec![&e, c.clone(), ab];
+    for count in [3, 4] {
+        assert!(!client.verify(&root, c, &3, &count, &duplicated));
+        assert!(!client.verify(&root, c, &3, &count, &tree.proof(2)));
+        assert!(!client.verify(&root, c, &2, &count, &duplicated));
+    }
+}
+
+#[test]
+fn test_verify_sorted() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+
+    for n in 1..=7 {
+        let leaves = leaves(&e, n);
+        let tree = MerkleTree::<Sha256>::new_sorted(&e, &leaves);
+        let root = tree.root();
+        assert_eq!(merkle::root_sorted::<Sha256>(&e, &tree.leaves()), root);
+
+        for (i, leaf) in leaves.iter().enumerate() {
+            assert!(client.verify_sorted(&root, leaf, &tree.proof(i as u32)));
+        }
+    }
+
+    let leaves = leaves(&e, 4);
+    let tree = MerkleTree::<Sha256>::new_sorted(&e, &leaves);
+    let other = e.crypto().sha256(&Bytes::from_array(&e, &[9]));
+    assert!(!client.verify_sorted(&tree.root(), &other, &tree.proof(0)));
+}
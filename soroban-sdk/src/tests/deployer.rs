@@ -0,0 +1,203 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contractimpl,
+    testutils::{Address as _, Ledger},
+    vec, Address, BytesN, Env, IntoVal, Symbol,
+};
+
+mod constructorcontract {
+    use crate as soroban_sdk;
+    soroban_sdk::contractimport!(
+        file = "../target/wasm32-unknown-unknown/release/test_constructor.wasm"
+    );
+}
+
+mod datacontract {
+    use crate as soroban_sdk;
+    soroban_sdk::contractimport!(
+        file = "../target/wasm32-unknown-unknown/release/test_contract_data.wasm"
+    );
+}
+
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn predict(env: Env, salt: BytesN<32>) -> Address {
+        env.deployer().with_current_contract(&salt).id()
+    }
+
+    pub fn deploy(env: Env, wasm_hash: BytesN<32>, salt: BytesN<32>) -> Address {
+        env.deployer()
+            .with_current_contract(&salt)
+            .deploy(&wasm_hash)
+    }
+
+    pub fn deploy_init(
+        env: Env,
+        wasm_hash: BytesN<32>,
+        salt: BytesN<32>,
+        init_fn: Symbol,
+        key: Symbol,
+    ) -> Address {
+        env.deployer()
+            .with_current_contract(&salt)
+            .deploy_with_init(
+                &wasm_hash,
+                &init_fn,
+                vec![
+                    &env,
+                    key.into_val(&env),
+                    Symbol::short("init").into_val(&env),
+                ],
+            )
+    }
+
+    pub fn deploy_ctor(
+        env: Env,
+        wasm_hash: BytesN<32>,
+        salt: BytesN<32>,
+        admin: Address,
+    ) -> Address {
+        env.deployer()
+            .with_current_contract(&salt)
+            .deploy_with_constructor(&wasm_hash, (admin,))
+    }
+
+    pub fn deploy_client(
+        env: Env,
+        wasm_hash: BytesN<32>,
+        salt: BytesN<32>,
+        key: Symbol,
+    ) -> Address {
+        let client = env
+            .deployer()
+            .with_current_contract(&salt)
+            .deploy_with_client(&wasm_hash, |client: &datacontract::Client| {
+                client.put(&key, &Symbol::short("init"))
+            });
+        client.address
+    }
+}
+
+#[test]
+fn test_predict_deployed_contract_id() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+    let wasm_hash = e.install_contract_wasm(&[0u8; 100]);
+
+    let salt = BytesN::from_array(&e, &[1; 32]);
+    let predicted = client.predict(&salt);
+    let other = e.deployer().with_other_contract(&contract_id, &salt).id();
+    assert_eq!(predicted, other);
+
+    let deployed = client.deploy(&wasm_hash, &salt);
+    assert_eq!(deployed, predicted);
+
+    // Other salts derive other IDs.
+    let salt = BytesN::from_array(&e, &[2; 32]);
+    assert_ne!(client.predict(&salt), predicted);
+    assert_eq!(client.deploy(&wasm_hash, &salt), client.predict(&salt));
+}
+
+#[test]
+fn test_predicted_contract_id_depends_on_network() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+    let wasm_hash = e.install_contract_wasm(&[0u8; 100]);
+    let salt = BytesN::from_array(&e, &[1; 32]);
+
+    let predicted = client.predict(&salt);
+    e.ledger().with_mut(|li| li.network_id = [7; 32]);
+    let predicted_other_network = client.predict(&salt);
+    assert_ne!(predicted, predicted_other_network);
+    assert_eq!(client.deploy(&wasm_hash, &salt), predicted_other_network);
+}
+
+#[test]
+fn test_deploy_with_init() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+    let wasm_hash = e.install_contract_wasm(datacontract::WASM);
+    let salt = BytesN::from_array(&e, &[1; 32]);
+    let key = Symbol::short("key");
+
+    let deployed = client.deploy_init(&wasm_hash, &salt, &Symbol::short("put"), &key);
+    assert_eq!(deployed, client.predict(&salt));
+    let deployed_client = datacontract::Client::new(&e, &deployed);
+    assert_eq!(deployed_client.get(&key), Some(Symbol::short("init")));
+}
+
+#[test]
+fn test_deploy_with_init_fails_when_init_fails() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+    let wasm_hash = e.install_contract_wasm(datacontract::WASM);
+    let salt = BytesN::from_array(&e, &[1; 32]);
+    let key = Symbol::short("key");
+
+    let res = client.try_deploy_init(&wasm_hash, &salt, &Symbol::short("missing"), &key);
+    assert!(res.is_err());
+
+    // The deployment is discarded with the failed initialization.
+    let predicted = client.predict(&salt);
+    let deployed_client = datacontract::Client::new(&e, &predicted);
+    assert!(deployed_client.try_get(&key).is_err());
+}
+
+#[test]
+fn test_deploy_with_client() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+    let wasm_hash = e.install_contract_wasm(datacontract::WASM);
+    let salt = BytesN::from_array(&e, &[1; 32]);
+    let key = Symbol::short("key");
+
+    let deployed = client.deploy_client(&wasm_hash, &salt, &key);
+    assert_eq!(deployed, client.predict(&salt));
+    let deployed_client = datacontract::Client::new(&e, &deployed);
+    assert_eq!(deployed_client.get(&key), Some(Symbol::short("init")));
+}
+
+#[test]
+fn test_deploy_with_constructor() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+    let wasm_hash = e.install_contract_wasm(constructorcontract::WASM);
+    let salt = BytesN::from_array(&e, &[1; 32]);
+    let admin = Address::random(&e);
+
+    let deployed = client.deploy_ctor(&wasm_hash, &salt, &admin);
+    assert_eq!(deployed, client.predict(&salt));
+    let deployed_client = constructorcontract::Client::new(&e, &deployed);
+    assert_eq!(deployed_client.admin(), admin);
+
+    // The constructor cannot be invoked by anyone but the deployer, even with
+    // the salt.
+    let res = e.try_invoke_contract::<(), soroban_sdk::Error>(
+        &deployed,
+        &Symbol::new(&e, "__constructor"),
+        (salt, Address::random(&e)).into_val(&e),
+    );
+    assert!(res.is_err());
+    assert_eq!(deployed_client.admin(), admin);
+}
+
+#[test]
+fn test_register_contract_wasm_with_constructor() {
+    let e = Env::default();
+    let admin = Address::random(&e);
+    let contract_id = e.register_contract_wasm_with_constructor(
+        None,
+        constructorcontract::WASM,
+        (admin.clone(),),
+    );
+    let client = constructorcontract::Client::new(&e, &contract_id);
+    assert_eq!(client.admin(), admin);
+}
@@ -0,0 +1,125 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contractevent, contractimpl,
+    testutils::{Address as _, Events},
+    vec, Address, Env, IntoVal, Symbol,
+};
+
+#[contractevent]
+pub struct Ping {
+    #[topic]
+    pub count: u32,
+}
+
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn emit(env: Env, count: u32) {
+        env.events()
+            .publish((Symbol::short("count"), count), Symbol::short("data"));
+        Ping { count }.publish(&env);
+    }
+}
+
+#[test]
+fn test_clear_and_take() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+
+    client.emit(&1);
+    assert_eq!(e.events().all().len(), 2);
+
+    e.events().clear();
+    assert_eq!(e.events().all().len(), 0);
+
+    client.emit(&2);
+    let taken = e.events().take();
+    assert_eq!(
+        taken.slice(..1),
+        vec![
+            &e,
+            (
+                contract_id.clone(),
+                (Symbol::short("count"), 2u32).into_val(&e),
+                Symbol::short("data").into_val(&e),
+            )
+        ]
+    );
+    assert_eq!(taken.len(), 2);
+    assert_eq!(e.events().all().len(), 0);
+    assert_eq!(e.events().take().len(), 0);
+}
+
+#[test]
+fn test_since_checkpoint() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+
+    client.emit(&1);
+    let checkpoint = e.events().checkpoint();
+    client.emit(&2);
+
+    let since = e.events().since(&checkpoint);
+    assert_eq!(since.len(), 2);
+    assert_eq!(
+        since.get_unchecked(0).1,
+        (Symbol::short("count"), 2u32).into_val(&e)
+    );
+    assert_eq!(e.events().all().len(), 4);
+
+    // Cleared events are excluded even if they follow the checkpoint.
+    e.events().clear();
+    assert_eq!(e.events().since(&checkpoint).len(), 0);
+}
+
+#[test]
+fn test_filter() {
+    let e = Env::default();
+    let contract_id_a = e.register_contract(None, Contract);
+    let contract_id_b = e.register_contract(None, Contract);
+    ContractClient::new(&e, &contract_id_a).emit(&1);
+    ContractClient::new(&e, &contract_id_b).emit(&2);
+
+    let from_b = e.events().from_contract(&contract_id_b);
+    assert_eq!(from_b.len(), 2);
+    assert!(from_b
+        .iter_unchecked()
+        .all(|(id, _, _)| id == contract_id_b));
+    assert_eq!(e.events().from_contract(&Address::random(&e)), vec![&e]);
+
+    let pings = e.events().with_topic_prefix((Symbol::short("ping"),));
+    assert_eq!(pings.len(), 2);
+    let count_2 = e.events().with_topic_prefix((Symbol::short("count"), 2u32));
+    assert_eq!(count_2.len(), 1);
+    assert_eq!(count_2.get_unchecked(0).0, contract_id_b);
+    assert_eq!(e.events().with_topic_prefix(()).len(), 4);
+}
+
+#[test]
+fn test_assert_contains() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    ContractClient::new(&e, &contract_id).emit(&1);
+
+    e.events().assert_contains(
+        &contract_id,
+        (Symbol::short("count"), 1u32),
+        Symbol::short("data"),
+    );
+    e.events()
+        .assert_contains_event(&contract_id, &Ping { count: 1 });
+}
+
+#[test]
+#[should_panic(expected = "expected event was not published")]
+fn test_assert_contains_fails() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    ContractClient::new(&e, &contract_id).emit(&1);
+
+    e.events()
+        .assert_contains_event(&contract_id, &Ping { count: 2 });
+}
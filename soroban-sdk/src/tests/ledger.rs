@@ -0,0 +1,89 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contractimpl,
+    testutils::{Ledger, DEFAULT_LEDGER_CLOSE_TIME},
+    Env,
+};
+
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn now(env: Env) -> (u32, u64) {
+        (env.ledger().sequence(), env.ledger().timestamp())
+    }
+}
+
+fn setup() -> (Env, ContractClient<'static>) {
+    let e = Env::default();
+    e.ledger().with_mut(|li| {
+        li.sequence_number = 10;
+        li.timestamp = 1000;
+    });
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+    (e, client)
+}
+
+#[test]
+fn test_advance_by_seconds() {
+    let (e, client) = setup();
+    e.ledger().advance_by_seconds(60);
+    assert_eq!(client.now(), (10, 1060));
+}
+
+#[test]
+fn test_advance_ledgers() {
+    let (e, client) = setup();
+    assert_eq!(e.ledger().close_time(), DEFAULT_LEDGER_CLOSE_TIME);
+    e.ledger().advance_ledgers(3);
+    assert_eq!(client.now(), (13, 1000 + 3 * DEFAULT_LEDGER_CLOSE_TIME));
+
+    e.ledger().set_close_time(6);
+    assert_eq!(e.ledger().close_time(), 6);
+    e.ledger().advance_ledgers(2);
+    assert_eq!(client.now(), (15, 1027));
+}
+
+#[test]
+fn test_jump_to_timestamp() {
+    let (e, client) = setup();
+    e.ledger().jump_to_timestamp(5000);
+    assert_eq!(client.now(), (10, 5000));
+    e.ledger().jump_to_timestamp(5000);
+    assert_eq!(client.now(), (10, 5000));
+}
+
+#[test]
+#[should_panic(expected = "cannot jump to timestamp 999 before the ledger timestamp 1000")]
+fn test_jump_to_timestamp_backwards() {
+    let (e, _) = setup();
+    e.ledger().jump_to_timestamp(999);
+}
+
+#[test]
+fn test_with_ledger() {
+    let (e, client) = setup();
+    let result = e.ledger().with_ledger(|| {
+        e.ledger().set_close_time(10);
+        e.ledger().advance_ledgers(5);
+        client.now()
+    });
+    assert_eq!(result, (15, 1050));
+    assert_eq!(client.now(), (10, 1000));
+    assert_eq!(e.ledger().close_time(), DEFAULT_LEDGER_CLOSE_TIME);
+}
+
+#[test]
+fn test_with_ledger_restores_on_panic() {
+    extern crate std;
+    let (e, client) = setup();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        e.ledger().with_ledger(|| {
+            e.ledger().advance_ledgers(5);
+            panic!("failed");
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(client.now(), (10, 1000));
+}
@@ -0,0 +1,274 @@
+use crate as soroban_sdk;
+use proptest::prelude::*;
+use soroban_sdk::{
+    contractimpl, contracttype,
+    math::{FixedPoint, Rounding},
+    Env, I256, U256,
+};
+
+const SCALE_7: i128 = 10_000_000;
+const SCALE_18: u128 = 1_000_000_000_000_000_000;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pool {
+    pub reserve_a: i128,
+    pub reserve_b: i128,
+    pub liquidity: U256,
+}
+
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn quote(pool: Pool, amount_a: i128) -> i128 {
+        amount_a.mul_div_floor(&pool.reserve_b, &pool.reserve_a)
+    }
+
+    pub fn share(pool: Pool, liquidity: U256, total: U256) -> U256 {
+        pool.liquidity.mul_div_floor(&liquidity, &total)
+    }
+}
+
+fn floor_div(n: i128, d: i128) -> i128 {
+    let q = n / d;
+    if n % d != 0 && (n < 0) != (d < 0) {
+        q - 1
+    } else {
+        q
+    }
+}
+
+fn ceil_div(n: i128, d: i128) -> i128 {
+    let q = n / d;
+    if n % d != 0 && (n < 0) == (d < 0) {
+        q + 1
+    } else {
+        q
+    }
+}
+
+#[test]
+fn test_contracttype_fields() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+
+    let pool = Pool {
+        reserve_a: 3 * SCALE_7,
+        reserve_b: 7 * SCALE_7,
+        liquidity: U256::from_u128(&e, u128::MAX),
+    };
+    assert_eq!(client.quote(&pool, &SCALE_7), 23_333_333);
+    assert_eq!(
+        client.share(
+            &pool,
+            &U256::from_u128(&e, u128::MAX),
+            &U256::from_u128(&e, u128::MAX)
+        ),
+        u128::MAX
+    );
+}
+
+#[test]
+fn test_rounding_of_negative_results() {
+    assert_eq!((-7i128).mul_div_floor(&1, &2), -4);
+    assert_eq!((-7i128).mul_div_ceil(&1, &2), -3);
+    assert_eq!(7i128.mul_div_floor(&-1, &2), -4);
+    assert_eq!(7i128.mul_div_floor(&1, &-2), -4);
+    assert_eq!((-7i128).mul_div_floor(&-1, &2), 3);
+    assert_eq!((-1i128).mul_div_ceil(&1, &3), 0);
+    assert_eq!((-1i128).mul_div_floor(&1, &3), -1);
+}
+
+#[test]
+fn test_overflow() {
+    assert_eq!(
+        u128::MAX.checked_mul_div(&u128::MAX, &u128::MAX, Rounding::Ceil),
+        Some(u128::MAX)
+    );
+    assert_eq!(u128::MAX.checked_mul_div(&2, &1, Rounding::Floor), None);
+    assert_eq!(1u128.checked_mul_div(&1, &0, Rounding::Floor), None);
+    assert_eq!(i128::MIN.checked_mul_div(&-1, &1, Rounding::Floor), None);
+    assert_eq!(
+        i128::MIN.checked_mul_div(&1, &1, Rounding::Floor),
+        Some(i128::MIN)
+    );
+    assert_eq!(
+        i128::MIN.checked_mul_div(&-1, &-1, Rounding::Floor),
+        Some(i128::MIN)
+    );
+    assert_eq!(
+        i128::MAX.checked_mul_div(&-1, &1, Rounding::Floor),
+        Some(-i128::MAX)
+    );
+    // -(2^128 + 1) / 2 rounds up to the minimum value, and down past it.
+    let (a, b) = (
+        59_649_589_127_497_217i128,
+        -5_704_689_200_685_129_054_721i128,
+    );
+    assert_eq!(a.checked_mul_div(&b, &2, Rounding::Ceil), Some(i128::MIN));
+    assert_eq!(a.checked_mul_div(&b, &2, Rounding::Floor), None);
+    assert_eq!((-1i128).checked_sqrt(&1), None);
+    assert_eq!(1i128.checked_sqrt(&-1), None);
+    assert_eq!(SCALE_7.checked_pow_fixed(2, &0, Rounding::Floor), None);
+    assert_eq!(
+        (2 * SCALE_7).checked_pow_fixed(127, &SCALE_7, Rounding::Floor),
+        None
+    );
+}
+
+#[test]
+#[should_panic(expected = "attempt to multiply and divide with overflow or by zero")]
+fn test_mul_div_by_zero_panics() {
+    1i128.mul_div_floor(&1, &0);
+}
+
+#[test]
+fn test_256_bit_types() {
+    let e = Env::default();
+    let u = |v: u128| U256::from_u128(&e, v);
+    let i = |v: i128| I256::from_i128(&e, v);
+
+    // Intermediate products wider than 256 bits do not overflow.
+    let max = u(u128::MAX).mul(&u(u128::MAX));
+    assert_eq!(max.mul_div_floor(&max, &max), max);
+    assert_eq!(max.mul_div_ceil(&max.sub(&u(1)), &max), max.sub(&u(1)));
+    assert_eq!(max.checked_mul_div(&u(2), &u(1), Rounding::Floor), None);
+
+    assert_eq!(i(-7).mul_div_floor(&i(1), &i(2)), -4i32);
+    assert_eq!(i(-7).mul_div_ceil(&i(1), &i(2)), -3i32);
+    assert_eq!(i(-1).checked_sqrt(&i(1)), None);
+
+    // sqrt(2) with 18 decimal places.
+    assert_eq!(
+        u(2 * SCALE_18).sqrt(&u(SCALE_18)),
+        1_414_213_562_373_095_048u128
+    );
+    assert_eq!(max.sqrt(&max), max);
+    assert_eq!(
+        u(SCALE_18 / 2).pow_fixed(3, &u(SCALE_18), Rounding::Floor),
+        SCALE_18 / 8
+    );
+    assert_eq!(
+        i(-(SCALE_7 / 2)).pow_fixed(3, &i(SCALE_7), Rounding::Floor),
+        -SCALE_7 / 8
+    );
+}
+
+#[test]
+fn test_pow_fixed() {
+    assert_eq!(12_345i128.pow_fixed(0, &SCALE_7, Rounding::Floor), SCALE_7);
+    assert_eq!(12_345i128.pow_fixed(1, &SCALE_7, Rounding::Floor), 12_345);
+    // 1.0000001^10000000 = 2.71828169..., and as rounding compounds over the
+    // multiplications the results bound it from below and above.
+    let rate = SCALE_7 + 1;
+    let floor = rate.pow_fixed(10_000_000, &SCALE_7, Rounding::Floor);
+    let ceil = rate.pow_fixed(10_000_000, &SCALE_7, Rounding::Ceil);
+    assert!(floor <= 27_182_816);
+    assert!(ceil >= 27_182_817);
+    // -1.5^3 = -3.375
+    assert_eq!(
+        (-15 * SCALE_7 / 10).pow_fixed(3, &SCALE_7, Rounding::Ceil),
+        -33_750_000
+    );
+    // -1.5^3 = -3.375 with a scale of 10 is rounded to -3.4 or -3.3.
+    assert_eq!((-15i128).pow_fixed(3, &10, Rounding::Floor), -34);
+    assert_eq!((-15i128).pow_fixed(3, &10, Rounding::Ceil), -33);
+    assert_eq!((-15i128).pow_fixed(2, &10, Rounding::Floor), 22);
+    assert_eq!((-15i128).pow_fixed(2, &10, Rounding::Ceil), 23);
+}
+
+proptest! {
+    #[test]
+    fn prop_mul_div_matches_i128(x in any::<i64>(), y in any::<i64>(), d in any::<i64>()) {
+        prop_assume!(d != 0);
+        let (x, y, d) = (x as i128, y as i128, d as i128);
+        let n = x * y;
+        prop_assert_eq!(x.mul_div_floor(&y, &d), floor_div(n, d));
+        prop_assert_eq!(x.mul_div_ceil(&y, &d), ceil_div(n, d));
+    }
+
+    #[test]
+    fn prop_mul_div_u128(x in any::<u128>(), y in any::<u128>(), d in 1..=u128::MAX) {
+        let e = Env::default();
+        let n = U256::from_u128(&e, x).mul(&U256::from_u128(&e, y));
+        let d256 = U256::from_u128(&e, d);
+        let q = n.div(&d256);
+        let exact = n.rem(&d256) == 0u32;
+        let floor = q.to_u128();
+        let ceil = if exact { q.to_u128() } else { q.add(&U256::from_u128(&e, 1)).to_u128() };
+        prop_assert_eq!(x.checked_mul_div(&y, &d, Rounding::Floor), floor);
+        prop_assert_eq!(x.checked_mul_div(&y, &d, Rounding::Ceil), ceil);
+    }
+
+    #[test]
+    fn prop_mul_div_i128(x in any::<i128>(), y in any::<i128>(), d in any::<i128>()) {
+        prop_assume!(d != 0);
+        let e = Env::default();
+        let n = I256::from_i128(&e, x).mul(&I256::from_i128(&e, y));
+        let d256 = I256::from_i128(&e, d);
+        let floor = x.checked_mul_div(&y, &d, Rounding::Floor);
+        let ceil = x.checked_mul_div(&y, &d, Rounding::Ceil);
+        // The results are within one of each other, and bound the exact
+        // quotient: floor * d <= n < (floor + 1) * d for positive d.
+        if let (Some(floor), Some(ceil)) = (floor, ceil) {
+            prop_assert!(ceil - floor <= 1);
+            let lower = I256::from_i128(&e, floor).mul(&d256);
+            let upper = I256::from_i128(&e, floor).add(&I256::from_i128(&e, 1)).mul(&d256);
+            if d > 0 {
+                prop_assert!(lower <= n && n < upper);
+            } else {
+                prop_assert!(lower >= n && n > upper);
+            }
+            prop_assert_eq!(ceil - floor == 0, n.rem(&d256) == 0i32);
+        } else {
+            // Results that do not fit are outside the range of i128.
+            let q = n.div(&d256);
+            prop_assert!(!matches!(
+                q.to_i128(),
+                Some(q) if q != i128::MIN && q != i128::MAX
+            ));
+        }
+    }
+
+    #[test]
+    fn prop_sqrt_u128(x in any::<u128>()) {
+        let r = x.sqrt(&1);
+        prop_assert!(r * r <= x);
+        if let Some(s) = (r + 1).checked_mul(r + 1) {
+            prop_assert!(s > x);
+        }
+    }
+
+    #[test]
+    fn prop_sqrt_scaled(x in any::<u128>()) {
+        let e = Env::default();
+        let r = U256::from_u128(&e, x.sqrt(&SCALE_18));
+        let n = U256::from_u128(&e, x).mul(&U256::from_u128(&e, SCALE_18));
+        let next = r.add(&U256::from_u128(&e, 1));
+        prop_assert!(r.mul(&r) <= n);
+        prop_assert!(next.mul(&next) > n);
+    }
+
+    #[test]
+    fn prop_pow_fixed(x in -(SCALE_7 * 100)..SCALE_7 * 100, exp in 0u32..8) {
+        let floor = x.pow_fixed(exp, &SCALE_7, Rounding::Floor);
+        let ceil = x.pow_fixed(exp, &SCALE_7, Rounding::Ceil);
+        if exp == 0 {
+            prop_assert_eq!((floor, ceil), (SCALE_7, SCALE_7));
+        } else {
+            // The exact result is x^exp / scale^(exp - 1), so the results
+            // bound it when multiplied by scale^(exp - 1).
+            let e = Env::default();
+            let n = I256::from_i128(&e, x).pow(exp);
+            let d = I256::from_i128(&e, SCALE_7).pow(exp - 1);
+            prop_assert!(I256::from_i128(&e, floor).mul(&d) <= n);
+            prop_assert!(n <= I256::from_i128(&e, ceil).mul(&d));
+        }
+        if exp == 2 {
+            prop_assert_eq!(floor, x.mul_div_floor(&x, &SCALE_7));
+            prop_assert_eq!(ceil, x.mul_div_ceil(&x, &SCALE_7));
+        }
+    }
+}
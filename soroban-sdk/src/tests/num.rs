@@ -0,0 +1,397 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    xdr::{ScValType, ToXdr},
+    Bytes, Env, IntoVal, RawVal, TryFromVal, I256, U256,
+};
+
+extern crate std;
+
+const U128_VALUES: [u128; 9] = [
+    0,
+    1,
+    2,
+    3,
+    255,
+    1 << 56,
+    u64::MAX as u128,
+    (1 << 100) + 12345,
+    u128::MAX,
+];
+
+const I128_VALUES: [i128; 12] = [
+    0,
+    1,
+    -1,
+    2,
+    -2,
+    255,
+    -(1 << 56),
+    i64::MIN as i128,
+    i64::MAX as i128,
+    -(1 << 100) - 12345,
+    i128::MIN,
+    i128::MAX,
+];
+
+fn u256_max(env: &Env) -> U256 {
+    U256::from_be_bytes(env, &Bytes::from_array(env, &[0xff; 32]))
+}
+
+fn i256_max(env: &Env) -> I256 {
+    let mut bytes = [0xff; 32];
+    bytes[0] = 0x7f;
+    I256::from_be_bytes(env, &Bytes::from_array(env, &bytes))
+}
+
+fn i256_min(env: &Env) -> I256 {
+    let mut bytes = [0; 32];
+    bytes[0] = 0x80;
+    I256::from_be_bytes(env, &Bytes::from_array(env, &bytes))
+}
+
+#[test]
+fn test_u256_xdr_type() {
+    let env = Env::default();
+    // Values are converted to and from the host by their ScVal XDR, for both
+    // small and object values.
+    for v in [0, u128::MAX] {
+        let xdr = U256::from_u128(&env, v).to_xdr(&env);
+        assert_eq!(xdr.len(), 36);
+        assert_eq!(
+            xdr.slice(..4),
+            Bytes::from_array(&env, &(ScValType::U256 as u32).to_be_bytes())
+        );
+    }
+    for v in [-1, i128::MIN] {
+        let xdr = I256::from_i128(&env, v).to_xdr(&env);
+        assert_eq!(xdr.len(), 36);
+        assert_eq!(
+            xdr.slice(..4),
+            Bytes::from_array(&env, &(ScValType::I256 as u32).to_be_bytes())
+        );
+    }
+}
+
+#[test]
+fn test_u256_conversions() {
+    let env = Env::default();
+    for v in U128_VALUES {
+        let u = U256::from_u128(&env, v);
+        assert_eq!(u.to_u128(), Some(v));
+        assert_eq!(u, v);
+
+        let raw: RawVal = u.into_val(&env);
+        assert_eq!(U256::try_from_val(&env, &raw), Ok(U256::from_u128(&env, v)));
+        let converted: U256 = v.into_val(&env);
+        assert_eq!(u128::try_from_val(&env, &converted), Ok(v));
+
+        let mut bytes = [0u8; 32];
+        bytes[16..].copy_from_slice(&v.to_be_bytes());
+        assert_eq!(u.to_be_bytes(), Bytes::from_array(&env, &bytes));
+        assert_eq!(U256::from_be_bytes(&env, &u.to_be_bytes()), u);
+    }
+
+    let max = u256_max(&env);
+    assert_eq!(max.to_u128(), None);
+    assert!(u128::try_from_val(&env, &max).is_err());
+    assert_eq!(max.to_be_bytes(), Bytes::from_array(&env, &[0xff; 32]));
+}
+
+#[test]
+fn test_i256_conversions() {
+    let env = Env::default();
+    for v in I128_VALUES {
+        let i = I256::from_i128(&env, v);
+        assert_eq!(i.to_i128(), Some(v));
+        assert_eq!(i, v);
+
+        let raw: RawVal = i.into_val(&env);
+        assert_eq!(I256::try_from_val(&env, &raw), Ok(I256::from_i128(&env, v)));
+        let converted: I256 = v.into_val(&env);
+        assert_eq!(i128::try_from_val(&env, &converted), Ok(v));
+
+        // Big-endian two's complement, sign extended to 32 bytes.
+        let mut bytes = if v < 0 { [0xff; 32] } else { [0; 32] };
+        bytes[16..].copy_from_slice(&v.to_be_bytes());
+        assert_eq!(i.to_be_bytes(), Bytes::from_array(&env, &bytes));
+        assert_eq!(I256::from_be_bytes(&env, &i.to_be_bytes()), i);
+    }
+
+    assert_eq!(i256_max(&env).to_i128(), None);
+    assert_eq!(i256_min(&env).to_i128(), None);
+    assert!(i128::try_from_val(&env, &i256_min(&env)).is_err());
+}
+
+#[test]
+#[should_panic(expected = "256-bit integers are 32 bytes long")]
+fn test_from_be_bytes_wrong_length() {
+    let env = Env::default();
+    U256::from_be_bytes(&env, &Bytes::from_array(&env, &[1; 31]));
+}
+
+#[test]
+fn test_u256_matches_u128() {
+    let env = Env::default();
+    let u = |v: u128| U256::from_u128(&env, v);
+    for a in U128_VALUES {
+        for b in U128_VALUES {
+            let (ua, ub) = (u(a), u(b));
+            assert_eq!(ua.cmp(&ub), a.cmp(&b));
+            assert_eq!(ua.partial_cmp(&b), a.partial_cmp(&b));
+            assert_eq!(a.partial_cmp(&ub), a.partial_cmp(&b));
+
+            // Sums and products of 128-bit values do not overflow 256 bits,
+            // and match those of u128 when they fit in 128 bits.
+            let sum = ua.checked_add(&ub).unwrap();
+            assert_eq!(sum.to_u128(), a.checked_add(b));
+            assert_eq!(ua.wrapping_add(&ub), sum);
+            assert_eq!(ua.saturating_add(&ub), sum);
+            assert_eq!(ua.add(&ub), sum);
+
+            assert_eq!(
+                ua.checked_sub(&ub).map(|r| r.to_u128()),
+                a.checked_sub(b).map(Some)
+            );
+            if a >= b {
+                assert_eq!(ua.sub(&ub), a - b);
+                assert_eq!(ua.wrapping_sub(&ub), a - b);
+                assert_eq!(ua.saturating_sub(&ub), a - b);
+            } else {
+                assert_eq!(ua.saturating_sub(&ub), 0u32);
+            }
+
+            let product = ua.checked_mul(&ub).unwrap();
+            assert_eq!(product.to_u128(), a.checked_mul(b));
+            assert_eq!(ua.wrapping_mul(&ub), product);
+            assert_eq!(ua.saturating_mul(&ub), product);
+            assert_eq!(ua.mul(&ub), product);
+
+            if b == 0 {
+                assert_eq!(ua.checked_div(&ub), None);
+                assert_eq!(ua.checked_rem(&ub), None);
+            } else {
+                assert_eq!(ua.div(&ub), a / b);
+                assert_eq!(ua.checked_div(&ub).unwrap(), a / b);
+                assert_eq!(ua.wrapping_div(&ub), a / b);
+                assert_eq!(ua.saturating_div(&ub), a / b);
+                assert_eq!(ua.rem(&ub), a % b);
+                assert_eq!(ua.checked_rem(&ub).unwrap(), a % b);
+                assert_eq!(ua.wrapping_rem(&ub), a % b);
+                // Division of the product recovers the operand, exercising
+                // division of values wider than 128 bits.
+                assert_eq!(product.div(&ub), a);
+                assert_eq!(product.add(&ua).rem(&ub), a % b);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_i256_matches_i128() {
+    let env = Env::default();
+    let i = |v: i128| I256::from_i128(&env, v);
+    for a in I128_VALUES {
+        for b in I128_VALUES {
+            let (ia, ib) = (i(a), i(b));
+            assert_eq!(ia.cmp(&ib), a.cmp(&b));
+            assert_eq!(ia.partial_cmp(&b), a.partial_cmp(&b));
+            assert_eq!(a.partial_cmp(&ib), a.partial_cmp(&b));
+
+            let sum = ia.checked_add(&ib).unwrap();
+            assert_eq!(sum.to_i128(), a.checked_add(b));
+            assert_eq!(ia.wrapping_add(&ib), sum);
+            assert_eq!(ia.saturating_add(&ib), sum);
+
+            let diff = ia.checked_sub(&ib).unwrap();
+            assert_eq!(diff.to_i128(), a.checked_sub(b));
+            assert_eq!(ia.wrapping_sub(&ib), diff);
+            assert_eq!(ia.saturating_sub(&ib), diff);
+
+            let product = ia.checked_mul(&ib).unwrap();
+            assert_eq!(product.to_i128(), a.checked_mul(b));
+            assert_eq!(ia.wrapping_mul(&ib), product);
+            assert_eq!(ia.saturating_mul(&ib), product);
+
+            if b == 0 {
+                assert_eq!(ia.checked_div(&ib), None);
+                assert_eq!(ia.checked_rem(&ib), None);
+            } else {
+                // The quotient and remainder round towards zero as for i128,
+                // which overflows only for i128::MIN / -1.
+                let q = ia.div(&ib);
+                assert_eq!(q.to_i128(), a.checked_div(b));
+                assert_eq!(ia.checked_div(&ib).unwrap(), q);
+                assert_eq!(ia.wrapping_div(&ib), q);
+                assert_eq!(ia.saturating_div(&ib), q);
+                assert_eq!(ia.rem(&ib), a.wrapping_rem(b));
+                assert_eq!(ia.checked_rem(&ib).unwrap(), a.wrapping_rem(b));
+                assert_eq!(product.div(&ib), a);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_u256_overflow() {
+    let env = Env::default();
+    let max = u256_max(&env);
+    let zero = U256::from_u128(&env, 0);
+    let one = U256::from_u128(&env, 1);
+    let two = U256::from_u128(&env, 2);
+
+    assert_eq!(max.checked_add(&one), None);
+    assert_eq!(max.wrapping_add(&one), 0u32);
+    assert_eq!(max.saturating_add(&one), max);
+
+    assert_eq!(zero.checked_sub(&one), None);
+    assert_eq!(zero.wrapping_sub(&one), max);
+    assert_eq!(zero.saturating_sub(&one), 0u32);
+
+    assert_eq!(max.checked_mul(&two), None);
+    assert_eq!(max.wrapping_mul(&two), max.sub(&one));
+    assert_eq!(max.wrapping_mul(&max), 1u32);
+    assert_eq!(max.saturating_mul(&two), max);
+
+    assert_eq!(max.div(&max), 1u32);
+    assert_eq!(max.rem(&two), 1u32);
+    assert_eq!(max.div(&two), max.shr(1));
+
+    assert_eq!(two.pow(255), one.shl(255));
+    assert_eq!(two.checked_pow(256), None);
+    assert_eq!(two.wrapping_pow(256), 0u32);
+    assert_eq!(two.saturating_pow(256), max);
+    assert_eq!(max.pow(0), 1u32);
+    assert_eq!(max.pow(1), max);
+    assert_eq!(zero.pow(0), 1u32);
+    assert_eq!(max.checked_pow(2), None);
+
+    assert_eq!(max.shl(255), one.shl(255));
+    assert_eq!(max.shr(255), 1u32);
+    assert_eq!(max.shl(0), max);
+    assert_eq!(max.checked_shl(256), None);
+    assert_eq!(max.checked_shr(256), None);
+    assert_eq!(max.wrapping_shl(257), max.shl(1));
+    assert_eq!(max.wrapping_shr(257), max.shr(1));
+    for bits in [1, 63, 64, 65, 127, 128, 129, 200] {
+        assert_eq!(one.shl(bits).shr(bits), 1u32);
+        assert_eq!(max.shr(bits).add(&one).shl(bits).wrapping_sub(&one), max);
+    }
+}
+
+#[test]
+fn test_i256_overflow() {
+    let env = Env::default();
+    let max = i256_max(&env);
+    let min = i256_min(&env);
+    let zero = I256::from_i128(&env, 0);
+    let one = I256::from_i128(&env, 1);
+    let neg_one = I256::from_i128(&env, -1);
+    let two = I256::from_i128(&env, 2);
+    let neg_two = I256::from_i128(&env, -2);
+
+    assert!(min < zero);
+    assert!(max > zero);
+    assert!(min < i128::MIN);
+    assert!(max > i128::MAX);
+    assert_eq!(min.add(&max), -1i32);
+
+    assert_eq!(max.checked_add(&one), None);
+    assert_eq!(max.wrapping_add(&one), min);
+    assert_eq!(max.saturating_add(&one), max);
+    assert_eq!(min.checked_add(&neg_one), None);
+    assert_eq!(min.saturating_add(&neg_one), min);
+
+    assert_eq!(min.checked_sub(&one), None);
+    assert_eq!(min.wrapping_sub(&one), max);
+    assert_eq!(min.saturating_sub(&one), min);
+    assert_eq!(max.checked_sub(&neg_one), None);
+    assert_eq!(max.saturating_sub(&neg_one), max);
+    assert_eq!(zero.checked_sub(&min), None);
+    assert_eq!(neg_one.sub(&min), max);
+
+    assert_eq!(min.checked_mul(&neg_one), None);
+    assert_eq!(min.wrapping_mul(&neg_one), min);
+    assert_eq!(min.saturating_mul(&neg_one), max);
+    assert_eq!(max.checked_mul(&two), None);
+    assert_eq!(max.saturating_mul(&two), max);
+    assert_eq!(max.saturating_mul(&neg_two), min);
+    assert_eq!(max.mul(&neg_one), min.add(&one));
+    assert_eq!(min.shr(1).mul(&two), min);
+
+    assert_eq!(min.checked_div(&neg_one), None);
+    assert_eq!(min.wrapping_div(&neg_one), min);
+    assert_eq!(min.saturating_div(&neg_one), max);
+    assert_eq!(min.checked_rem(&neg_one), None);
+    assert_eq!(min.wrapping_rem(&neg_one), 0i32);
+    assert_eq!(min.div(&max), -1i32);
+    assert_eq!(min.rem(&max), -1i32);
+    assert_eq!(max.div(&min), 0i32);
+    assert_eq!(max.rem(&min), max);
+
+    assert_eq!(neg_two.pow(255), min);
+    assert_eq!(two.checked_pow(255), None);
+    assert_eq!(two.saturating_pow(255), max);
+    assert_eq!(neg_two.checked_pow(256), None);
+    assert_eq!(neg_two.saturating_pow(256), max);
+    assert_eq!(neg_two.saturating_pow(257), min);
+    assert_eq!(neg_two.wrapping_pow(256), 0i32);
+    assert_eq!(neg_one.pow(1001), -1i32);
+
+    assert_eq!(min.shr(255), -1i32);
+    assert_eq!(max.shr(254), 1i32);
+    assert_eq!(neg_one.shl(255), min);
+    assert_eq!(one.shl(255), min);
+    assert_eq!(neg_two.shr(1), -1i32);
+    assert_eq!(I256::from_i128(&env, -5).shr(1), -3i32);
+    assert_eq!(min.checked_shr(256), None);
+}
+
+#[test]
+#[should_panic(expected = "attempt to add with overflow")]
+fn test_u256_add_overflow_panics() {
+    let env = Env::default();
+    u256_max(&env).add(&U256::from_u128(&env, 1));
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_u256_div_by_zero_panics() {
+    let env = Env::default();
+    U256::from_u128(&env, 1).div(&U256::from_u128(&env, 0));
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide with overflow")]
+fn test_i256_div_overflow_panics() {
+    let env = Env::default();
+    i256_min(&env).div(&I256::from_i128(&env, -1));
+}
+
+#[test]
+#[should_panic(expected = "attempt to shift left with overflow")]
+fn test_i256_shl_overflow_panics() {
+    let env = Env::default();
+    I256::from_i128(&env, 1).shl(256);
+}
+
+#[test]
+fn test_debug() {
+    let env = Env::default();
+    assert_eq!(
+        std::format!("{:?}", U256::from_u128(&env, 12345)),
+        "U256(12345)"
+    );
+    assert_eq!(
+        std::format!("{:?}", u256_max(&env)),
+        "U256(115792089237316195423570985008687907853269984665640564039457584007913129639935)"
+    );
+    assert_eq!(std::format!("{:?}", I256::from_i128(&env, -1)), "I256(-1)");
+    assert_eq!(
+        std::format!("{:?}", i256_min(&env)),
+        "I256(-57896044618658097711785492504343953926634992332820282019728792003956564819968)"
+    );
+    assert_eq!(
+        std::format!("{:?}", I256::from_i128(&env, 10_000_000_000_000_000_000)),
+        "I256(10000000000000000000)"
+    );
+}
@@ -0,0 +1,200 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    auth::Context,
+    contractimpl, contracttype,
+    testutils::{ed25519::Keypair, ContractAuthBuilder, MockAuth, MockAuthInvoke},
+    vec, xdr, Address, BytesN, Env, IntoVal, RawVal, Vec,
+};
+
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn hello(_env: Env, from: Address) {
+        from.require_auth();
+    }
+}
+
+pub struct Forwarder;
+
+#[contractimpl]
+impl Forwarder {
+    pub fn forward(env: Env, contract: Address, from: Address) {
+        from.require_auth();
+        ContractClient::new(&env, &contract).hello(&from);
+    }
+}
+
+#[contracttype]
+pub enum DataKey {
+    Signer,
+}
+
+pub struct AccountContract;
+
+#[contractimpl]
+impl AccountContract {
+    pub fn init(env: Env, signer: BytesN<32>) {
+        env.storage().set(&DataKey::Signer, &signer);
+    }
+
+    #[allow(non_snake_case)]
+    pub fn __check_auth(
+        env: Env,
+        signature_payload: BytesN<32>,
+        signatures: Vec<BytesN<64>>,
+        _auth_context: Vec<Context>,
+    ) {
+        let signer: BytesN<32> = env.storage().get(&DataKey::Signer).unwrap();
+        env.crypto().ed25519_verify(
+            &signer,
+            &signature_payload.into(),
+            &signatures.get_unchecked(0).unwrap(),
+        );
+    }
+}
+
+fn account_signer(keypair: &Keypair) -> impl Fn(&Env, &BytesN<32>) -> Vec<RawVal> + '_ {
+    move |env, payload| {
+        vec![
+            env,
+            keypair.sign_bytes(&payload.clone().into()).into_val(env),
+        ]
+    }
+}
+
+#[test]
+fn test_sign_auth_for_account_contract() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+
+    let keypair = Keypair::generate(&e);
+    let account = e.register_contract(None, AccountContract);
+    AccountContractClient::new(&e, &account).init(&keypair.public_key());
+
+    let invoke = MockAuthInvoke {
+        contract: &contract_id,
+        fn_name: "hello",
+        args: (&account,).into_val(&e),
+        sub_invokes: &[],
+    };
+    let auth = ContractAuthBuilder::new(&e, &account, &invoke).sign(&account_signer(&keypair));
+    e.set_auths(&[auth.clone()]);
+    client.hello(&account);
+
+    // The nonce has been consumed and the authorization cannot be replayed.
+    e.set_auths(&[auth.clone()]);
+    assert!(client.try_hello(&account).is_err());
+
+    // Authorizations built after use the next nonce.
+    let next = ContractAuthBuilder::new(&e, &account, &invoke).sign(&account_signer(&keypair));
+    assert_eq!(next.address_with_nonce.as_ref().unwrap().nonce, 1);
+    e.set_auths(&[next]);
+    client.hello(&account);
+
+    // Signatures by other signers are rejected by the account contract.
+    let other = Keypair::generate(&e);
+    let auth = ContractAuthBuilder::new(&e, &account, &invoke).sign(&account_signer(&other));
+    e.set_auths(&[auth]);
+    assert!(client.try_hello(&account).is_err());
+}
+
+#[test]
+fn test_sign_auth_payload_depends_on_nonce() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let account = e.register_contract(None, AccountContract);
+
+    let invoke = MockAuthInvoke {
+        contract: &contract_id,
+        fn_name: "hello",
+        args: (&account,).into_val(&e),
+        sub_invokes: &[],
+    };
+    let builder = ContractAuthBuilder::new(&e, &account, &invoke);
+    assert_eq!(
+        builder.signature_payload(),
+        ContractAuthBuilder::new(&e, &account, &invoke)
+            .nonce(0)
+            .signature_payload()
+    );
+    assert_ne!(
+        builder.signature_payload(),
+        ContractAuthBuilder::new(&e, &account, &invoke)
+            .nonce(1)
+            .signature_payload()
+    );
+}
+
+#[test]
+fn test_sign_auth_for_account() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+
+    let keypair = Keypair::generate(&e);
+    // The builder creates the account, so that its signatures can be checked.
+    let account = keypair.address();
+
+    let invoke = MockAuthInvoke {
+        contract: &contract_id,
+        fn_name: "hello",
+        args: (&account,).into_val(&e),
+        sub_invokes: &[],
+    };
+    let auth = ContractAuthBuilder::new(&e, &account, &invoke).sign(&keypair);
+    e.set_auths(&[auth]);
+    client.hello(&account);
+
+    let other = Keypair::generate(&e);
+    let auth = ContractAuthBuilder::new(&e, &account, &invoke).sign(&other);
+    e.set_auths(&[auth]);
+    assert!(client.try_hello(&account).is_err());
+}
+
+#[test]
+fn test_record_auths() {
+    extern crate std;
+
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let forwarder_id = e.register_contract(None, Forwarder);
+    let client = ForwarderClient::new(&e, &forwarder_id);
+
+    let keypair = Keypair::generate(&e);
+    let account = e.register_contract(None, AccountContract);
+    AccountContractClient::new(&e, &account).init(&keypair.public_key());
+
+    let auths = e.record_auths(|e| {
+        ForwarderClient::new(e, &forwarder_id).forward(&contract_id, &account);
+    });
+    assert_eq!(
+        auths,
+        std::vec![xdr::ContractAuth::from(MockAuth {
+            address: &account,
+            nonce: 0,
+            invoke: &MockAuthInvoke {
+                contract: &forwarder_id,
+                fn_name: "forward",
+                args: (&contract_id, &account).into_val(&e),
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &contract_id,
+                    fn_name: "hello",
+                    args: (&account,).into_val(&e),
+                    sub_invokes: &[],
+                }],
+            },
+        })]
+    );
+
+    // Recording leaves the environment unchanged, so the recorded
+    // authorizations can be signed and replayed with authorization enforced.
+    let auth = ContractAuthBuilder::from_auth(&e, &auths[0]).sign(&account_signer(&keypair));
+    e.set_auths(&[auth]);
+    client.forward(&contract_id, &account);
+
+    // Without the authorization the invocation fails.
+    e.set_auths(&[]);
+    assert!(client.try_forward(&contract_id, &account).is_err());
+}
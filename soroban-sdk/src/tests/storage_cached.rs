@@ -0,0 +1,102 @@
+use crate as soroban_sdk;
+use soroban_sdk::{contractimpl, storage::CachedStorage, Env, Symbol};
+
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn incr(env: Env, times: u32) -> u32 {
+        let storage = CachedStorage::new(&env);
+        let key = Symbol::short("count");
+        for _ in 0..times {
+            let count: u32 = storage.get(&key).unwrap_or(0);
+            storage.set(&key, &(count + 1));
+        }
+        storage.get(&key).unwrap_or(0)
+    }
+}
+
+#[test]
+fn test_flush_on_drop() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+
+    assert_eq!(client.incr(&3), 3);
+    assert_eq!(client.incr(&2), 5);
+    e.as_contract(&contract_id, || {
+        assert_eq!(e.storage().get::<_, u32>(&Symbol::short("count")), Some(5));
+    });
+}
+
+#[test]
+fn test_reads_and_writes() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+
+    e.as_contract(&contract_id, || {
+        let a = Symbol::short("a");
+        let b = Symbol::short("b");
+        e.storage().set(&a, &1);
+
+        let storage = CachedStorage::new(&e);
+        assert!(storage.has(&a));
+        assert_eq!(storage.get::<_, u32>(&a), Some(1));
+        assert_eq!(storage.saved_storage_calls(), 0);
+
+        // Reads of cached values do not call the host.
+        assert_eq!(storage.get::<_, u32>(&a), Some(1));
+        assert!(storage.has(&a));
+        assert_eq!(storage.saved_storage_calls(), 3);
+
+        // Reads of keys known to have no value do not call the host.
+        assert_eq!(storage.get::<_, u32>(&b), None);
+        assert_eq!(storage.get::<_, u32>(&b), None);
+        assert!(!storage.has(&b));
+        assert_eq!(storage.saved_storage_calls(), 5);
+
+        // Writes are not visible to the host until flushed.
+        storage.set(&a, &2);
+        storage.set(&b, &3);
+        storage.set(&b, &4);
+        assert_eq!(storage.saved_storage_calls(), 6);
+        assert_eq!(storage.get::<_, u32>(&a), Some(2));
+        assert_eq!(storage.get::<_, u32>(&b), Some(4));
+        assert_eq!(storage.saved_storage_calls(), 10);
+        assert_eq!(e.storage().get::<_, u32>(&a), Some(1));
+        assert!(!e.storage().has(&b));
+
+        storage.flush();
+        assert_eq!(e.storage().get::<_, u32>(&a), Some(2));
+        assert_eq!(e.storage().get::<_, u32>(&b), Some(4));
+
+        // Removes are not visible to the host until flushed.
+        storage.remove(&a);
+        assert!(!storage.has(&a));
+        assert_eq!(storage.get::<_, u32>(&a), None);
+        assert!(e.storage().has(&a));
+
+        storage.flush();
+        assert!(!e.storage().has(&a));
+        assert_eq!(e.storage().get::<_, u32>(&b), Some(4));
+    });
+}
+
+#[test]
+fn test_set_then_remove_before_flush() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+
+    e.as_contract(&contract_id, || {
+        let a = Symbol::short("a");
+        e.storage().set(&a, &1);
+
+        let storage = CachedStorage::new(&e);
+        storage.set(&a, &2);
+        storage.remove(&a);
+        assert_eq!(storage.saved_storage_calls(), 1);
+        drop(storage);
+
+        assert!(!e.storage().has(&a));
+    });
+}
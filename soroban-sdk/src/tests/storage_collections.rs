@@ -0,0 +1,160 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contractimpl,
+    storage::{StorageMap, StorageSet, StorageVec},
+    testutils::Address as _,
+    Address, Env, Symbol,
+};
+
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn push(env: Env, v: u32) -> u32 {
+        let vec = StorageVec::<u32>::new(&env, Symbol::short("vec"));
+        vec.push_back(&v);
+        vec.len()
+    }
+}
+
+#[test]
+fn test_storage_map() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+
+    let a = Address::random(&e);
+    let b = Address::random(&e);
+    e.as_contract(&contract_id, || {
+        let balances = StorageMap::<Address, i128>::new(&e, Symbol::short("balance"));
+        let allowances = StorageMap::<Address, i128>::new(&e, Symbol::short("allow"));
+
+        assert!(!balances.contains_key(&a));
+        assert_eq!(balances.get(&a), None);
+
+        balances.set(&a, &100);
+        balances.set(&b, &200);
+        assert!(balances.contains_key(&a));
+        assert_eq!(balances.get(&a), Some(100));
+        assert_eq!(balances.get(&b), Some(200));
+
+        // Maps with different prefixes do not share entries.
+        assert!(!allowances.contains_key(&a));
+        allowances.set(&a, &1);
+        assert_eq!(balances.get(&a), Some(100));
+
+        balances.set(&a, &50);
+        assert_eq!(balances.get(&a), Some(50));
+
+        balances.remove(&a);
+        assert!(!balances.contains_key(&a));
+        assert_eq!(balances.get(&b), Some(200));
+        assert_eq!(allowances.get(&a), Some(1));
+
+        // Each entry is stored under its own key.
+        assert_eq!(
+            e.storage()
+                .get::<_, i128>(&(Symbol::short("balance"), b.clone())),
+            Some(200)
+        );
+    });
+}
+
+#[test]
+fn test_storage_set() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+
+    let a = Address::random(&e);
+    let b = Address::random(&e);
+    e.as_contract(&contract_id, || {
+        let admins = StorageSet::<Address>::new(&e, Symbol::short("admins"));
+
+        assert!(!admins.contains(&a));
+        admins.insert(&a);
+        admins.insert(&a);
+        assert!(admins.contains(&a));
+        assert!(!admins.contains(&b));
+
+        admins.remove(&a);
+        admins.remove(&b);
+        assert!(!admins.contains(&a));
+    });
+}
+
+#[test]
+fn test_storage_vec() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+
+    e.as_contract(&contract_id, || {
+        let vec = StorageVec::<u32>::new(&e, Symbol::short("vec"));
+
+        assert!(vec.is_empty());
+        assert_eq!(vec.len(), 0);
+        assert_eq!(vec.get(0), None);
+        assert_eq!(vec.first(), None);
+        assert_eq!(vec.last(), None);
+        assert_eq!(vec.pop_back(), None);
+
+        vec.push_back(&10);
+        vec.push_back(&20);
+        vec.push_back(&30);
+        assert!(!vec.is_empty());
+        assert_eq!(vec.len(), 3);
+        assert_eq!(vec.get(0), Some(10));
+        assert_eq!(vec.get(2), Some(30));
+        assert_eq!(vec.get(3), None);
+        assert_eq!(vec.first(), Some(10));
+        assert_eq!(vec.last(), Some(30));
+
+        vec.set(1, &21);
+        assert_eq!(vec.get(1), Some(21));
+
+        let mut iter = vec.iter();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(10));
+        assert_eq!(iter.next_back(), Some(30));
+        assert_eq!(iter.next(), Some(21));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        assert_eq!(vec.pop_back(), Some(30));
+        assert_eq!(vec.len(), 2);
+        assert_eq!(vec.get(2), None);
+        assert!(!e.storage().has(&(Symbol::short("vec"), 2u32)));
+
+        vec.clear();
+        assert!(vec.is_empty());
+        assert!(!e.storage().has(&(Symbol::short("vec"), 0u32)));
+        assert!(!e.storage().has(&(Symbol::short("vec"),)));
+    });
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn test_storage_vec_set_out_of_bounds() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+
+    e.as_contract(&contract_id, || {
+        let vec = StorageVec::<u32>::new(&e, Symbol::short("vec"));
+        vec.push_back(&10);
+        vec.set(1, &20);
+    });
+}
+
+#[test]
+fn test_storage_vec_in_contract() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, Contract);
+    let client = ContractClient::new(&e, &contract_id);
+
+    assert_eq!(client.push(&1), 1);
+    assert_eq!(client.push(&2), 2);
+    assert_eq!(client.push(&3), 3);
+
+    e.as_contract(&contract_id, || {
+        let vec = StorageVec::<u32>::new(&e, Symbol::short("vec"));
+        assert_eq!(vec.iter().collect::<std::vec::Vec<_>>(), [1, 2, 3]);
+    });
+}
@@ -6,10 +6,19 @@
 mod sign;
 pub use sign::ed25519;
 
+mod merkle;
+pub use merkle::MerkleTree;
+
+pub(crate) mod auth_tree;
+pub use auth_tree::AuthorizedInvocation;
+
 mod mock_auth;
 pub use mock_auth::{MockAuth, MockAuthContract, MockAuthInvoke};
 
-use crate::{Env, RawVal, Vec};
+mod sign_auth;
+pub use sign_auth::{AuthSigner, ContractAuthBuilder};
+
+use crate::{Env, IntoVal, RawVal, Vec};
 
 #[doc(hidden)]
 pub trait ContractFunctionSet {
@@ -31,8 +40,66 @@ pub trait Ledger {
     fn with_mut<F>(&self, f: F)
     where
         F: FnMut(&mut LedgerInfo);
+
+    /// Get the number of seconds between ledgers closing, that is used by
+    /// [`advance_ledgers`][Ledger::advance_ledgers].
+    ///
+    /// Defaults to [`DEFAULT_LEDGER_CLOSE_TIME`].
+    fn close_time(&self) -> u64;
+
+    /// Set the number of seconds between ledgers closing, that is used by
+    /// [`advance_ledgers`][Ledger::advance_ledgers].
+    fn set_close_time(&self, seconds: u64);
+
+    /// Advance the ledger timestamp by the number of seconds.
+    ///
+    /// The ledger sequence number is unchanged.
+    fn advance_by_seconds(&self, seconds: u64);
+
+    /// Advance the ledger sequence number by the number of ledgers, and the
+    /// ledger timestamp by the [`close_time`][Ledger::close_time] of each
+    /// ledger.
+    fn advance_ledgers(&self, n: u32);
+
+    /// Set the ledger timestamp.
+    ///
+    /// The ledger sequence number is unchanged.
+    ///
+    /// ### Panics
+    ///
+    /// If the timestamp is before the current ledger timestamp.
+    fn jump_to_timestamp(&self, timestamp: u64);
+
+    /// Call the function, and restore the ledger info and close time that were
+    /// set before the function was called once it returns or panics.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{testutils::Ledger, Env};
+    ///
+    /// # #[cfg(feature = "testutils")]
+    /// # fn main() {
+    /// let env = Env::default();
+    /// let timestamp = env.ledger().timestamp();
+    /// env.ledger().with_ledger(|| {
+    ///     env.ledger().advance_by_seconds(60);
+    ///     assert_eq!(env.ledger().timestamp(), timestamp + 60);
+    /// });
+    /// assert_eq!(env.ledger().timestamp(), timestamp);
+    /// # }
+    /// # #[cfg(not(feature = "testutils"))]
+    /// # fn main() { }
+    /// ```
+    fn with_ledger<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce() -> R;
 }
 
+/// The default number of seconds between ledgers closing, used by
+/// [`Ledger::advance_ledgers`].
+pub const DEFAULT_LEDGER_CLOSE_TIME: u64 = 5;
+
 pub mod budget {
     use core::fmt::{Debug, Display};
 
@@ -137,23 +204,128 @@ pub mod budget {
 
 /// Test utilities for [`Events`][crate::events::Events].
 pub trait Events {
-    /// Returns all events that have been published by contracts.
+    /// Returns all events that have been published by contracts, since the
+    /// events were last cleared.
     ///
     /// Returns a [`Vec`] of three element tuples containing:
     /// - Contract ID
     /// - Event Topics as a [`Vec<RawVal>`]
     /// - Event Data as a [`RawVal`]
     fn all(&self) -> Vec<(crate::Address, Vec<RawVal>, RawVal)>;
+
+    /// Returns all events that have been published by contracts that decode
+    /// as events of type `E`, such as events defined with
+    /// [`contractevent`][crate::contractevent].
+    ///
+    /// Returns a [`Vec`][std::vec::Vec] of two element tuples containing:
+    /// - Contract ID
+    /// - Event
+    fn all_of<E: ContractEvent>(&self) -> std::vec::Vec<(crate::Address, E)>;
+
+    /// Clears all events that have been published, so that they are no longer
+    /// returned by the functions of this trait.
+    fn clear(&self);
+
+    /// Returns all events that have been published by contracts, and clears
+    /// them.
+    ///
+    /// Returns the same events as [`all`][Events::all].
+    fn take(&self) -> Vec<(crate::Address, Vec<RawVal>, RawVal)>;
+
+    /// Returns a checkpoint that marks the events that have been published so
+    /// far, for use with [`since`][Events::since].
+    fn checkpoint(&self) -> EventsCheckpoint;
+
+    /// Returns all events that have been published by contracts since the
+    /// checkpoint was taken.
+    fn since(&self, checkpoint: &EventsCheckpoint) -> Vec<(crate::Address, Vec<RawVal>, RawVal)>;
+
+    /// Returns all events that have been published by the contract.
+    fn from_contract(
+        &self,
+        contract_id: &crate::Address,
+    ) -> Vec<(crate::Address, Vec<RawVal>, RawVal)>;
+
+    /// Returns all events that have been published by contracts with topics
+    /// that begin with the given topics.
+    fn with_topic_prefix<T>(&self, prefix: T) -> Vec<(crate::Address, Vec<RawVal>, RawVal)>
+    where
+        T: IntoVal<Env, Vec<RawVal>>;
+
+    /// Asserts that an event with the topics and data has been published by
+    /// the contract.
+    ///
+    /// ### Panics
+    ///
+    /// If no such event has been published, with a message listing the
+    /// expected event and the events that have been published, with their
+    /// topics and data displayed as [`ScVal`][crate::xdr::ScVal]s.
+    fn assert_contains<T, D>(&self, contract_id: &crate::Address, topics: T, data: D)
+    where
+        T: IntoVal<Env, Vec<RawVal>>,
+        D: IntoVal<Env, RawVal>;
+
+    /// Asserts that the event has been published by the contract, such as
+    /// events defined with [`contractevent`][crate::contractevent].
+    ///
+    /// ### Panics
+    ///
+    /// If the event has not been published, in the same way as
+    /// [`assert_contains`][Events::assert_contains].
+    fn assert_contains_event<E: crate::events::Event>(
+        &self,
+        contract_id: &crate::Address,
+        event: &E,
+    );
+}
+
+/// A position in the events published in an [`Env`], returned by
+/// [`Events::checkpoint`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EventsCheckpoint(pub(crate) usize);
+
+/// Test utilities for decoding events defined with
+/// [`contractevent`][crate::contractevent].
+pub trait ContractEvent: Sized {
+    /// Decodes the event from the topics and data of a published event.
+    ///
+    /// Returns an error if the topics or data are not those of an event of
+    /// this type.
+    fn try_from_event(
+        env: &Env,
+        topics: &Vec<RawVal>,
+        data: &RawVal,
+    ) -> Result<Self, crate::ConversionError>;
 }
 
 /// Test utilities for [`Logger`][crate::logging::Logger].
 pub trait Logger {
-    /// Returns all diagnostic events that have been logged.
-    fn all(&self) -> std::vec::Vec<String>;
-    /// Prints all diagnostic events to stdout.
+    /// Returns all events that have been logged, parsed into log records.
+    fn all(&self) -> std::vec::Vec<LogRecord>;
+    /// Returns the lines that [`Logger::print`] prints, with log records
+    /// beneath the contract invocation that logged them.
+    fn lines(&self) -> std::vec::Vec<String>;
+    /// Prints all events that have been logged to stdout.
     fn print(&self);
 }
 
+/// A record of an event logged with [`log`][crate::log], returned by
+/// [`Logger::all`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LogRecord {
+    /// The contract that logged the event, or `None` if the event was not
+    /// logged by a contract.
+    pub contract_id: Option<crate::Address>,
+    /// The level the event was logged at.
+    pub level: crate::logging::Level,
+    /// The message.
+    pub msg: String,
+    /// The values logged with the message.
+    pub args: std::vec::Vec<crate::xdr::ScVal>,
+    /// The named fields logged with the message, in order of their names.
+    pub fields: std::vec::Vec<(String, crate::xdr::ScVal)>,
+}
+
 /// Test utilities for [`BytesN`][crate::BytesN].
 pub trait BytesN<const N: usize> {
     // Generate a BytesN filled with random bytes.
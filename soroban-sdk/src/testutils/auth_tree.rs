@@ -0,0 +1,171 @@
+#![cfg(any(test, feature = "testutils"))]
+
+use core::fmt::Write;
+
+use crate::{
+    logging::write_val, testutils::MockAuthInvoke, xdr, Address, BytesN, Env, RawVal, Symbol,
+    TryFromVal, Vec,
+};
+
+/// AuthorizedInvocation is an invocation that an address authorized, along
+/// with the invocations in its call tree that the address also authorized.
+///
+/// Authorized invocations compare equal to the [`MockAuthInvoke`] trees that
+/// describe the same invocations, once converted with [`From`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuthorizedInvocation {
+    pub contract: Address,
+    pub fn_name: Symbol,
+    pub args: Vec<RawVal>,
+    pub sub_invocations: std::vec::Vec<AuthorizedInvocation>,
+}
+
+impl AuthorizedInvocation {
+    pub(crate) fn from_xdr(env: &Env, invocation: &xdr::AuthorizedInvocation) -> Self {
+        let mut args = Vec::new(env);
+        for v in invocation.args.iter() {
+            args.push_back(RawVal::try_from_val(env, v).unwrap());
+        }
+        Self {
+            contract: Address::from_contract_id(&BytesN::from_array(
+                env,
+                &invocation.contract_id.0,
+            )),
+            fn_name: Symbol::try_from_val(env, &invocation.function_name).unwrap(),
+            args,
+            sub_invocations: invocation
+                .sub_invocations
+                .iter()
+                .map(|sub_invocation| Self::from_xdr(env, sub_invocation))
+                .collect(),
+        }
+    }
+
+    /// Asserts that the invocation tree matches the expected tree.
+    ///
+    /// ### Panics
+    ///
+    /// If the trees do not match, with a diff of the trees.
+    pub fn assert_matches(&self, expected: &MockAuthInvoke) {
+        let expected = AuthorizedInvocation::from(expected);
+        if *self != expected {
+            let mut expected_lines = std::vec::Vec::new();
+            expected.write_lines(&mut expected_lines, 0);
+            let mut actual_lines = std::vec::Vec::new();
+            self.write_lines(&mut actual_lines, 0);
+            panic!(
+                "authorized invocations do not match (- expected, + actual):\n{}",
+                diff_lines(&expected_lines, &actual_lines)
+            );
+        }
+    }
+
+    /// Writes the invocation tree as lines of the form `contract.fn(args)`,
+    /// with sub-invocations indented under their invocation.
+    pub(crate) fn write_lines(&self, lines: &mut std::vec::Vec<String>, depth: usize) {
+        let env = self.args.env();
+        let mut line = "  ".repeat(depth);
+        write_val(&mut line, &xdr::ScVal::try_from(&self.contract).unwrap());
+        line.push('.');
+        write_val(
+            &mut line,
+            &xdr::ScVal::try_from_val(env, &self.fn_name.to_raw()).unwrap(),
+        );
+        line.push('(');
+        for (i, arg) in self.args.iter_unchecked().enumerate() {
+            if i > 0 {
+                line.push_str(", ");
+            }
+            write_val(&mut line, &xdr::ScVal::try_from_val(env, &arg).unwrap());
+        }
+        line.push(')');
+        lines.push(line);
+        for sub_invocation in &self.sub_invocations {
+            sub_invocation.write_lines(lines, depth + 1);
+        }
+    }
+}
+
+impl<'a> From<&MockAuthInvoke<'a>> for AuthorizedInvocation {
+    fn from(invoke: &MockAuthInvoke<'a>) -> Self {
+        let env = invoke.args.env();
+        Self {
+            contract: invoke.contract.clone(),
+            fn_name: Symbol::new(env, invoke.fn_name),
+            args: invoke.args.clone(),
+            sub_invocations: invoke.sub_invokes.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<'a> From<MockAuthInvoke<'a>> for AuthorizedInvocation {
+    fn from(invoke: MockAuthInvoke<'a>) -> Self {
+        (&invoke).into()
+    }
+}
+
+/// Writes the authorizations as lines, with each address followed by the
+/// invocation tree it authorized.
+pub(crate) fn write_auths_lines(
+    auths: &[(Address, AuthorizedInvocation)],
+) -> std::vec::Vec<String> {
+    let mut lines = std::vec::Vec::new();
+    for (address, invocation) in auths {
+        let mut line = String::new();
+        write_val(&mut line, &xdr::ScVal::try_from(address).unwrap());
+        lines.push(line);
+        invocation.write_lines(&mut lines, 1);
+    }
+    lines
+}
+
+/// Returns a line diff of the expected and actual lines, with lines only in
+/// the expected prefixed by `-`, and lines only in the actual prefixed by `+`.
+pub(crate) fn diff_lines(expected: &[String], actual: &[String]) -> String {
+    // Lengths of the longest common subsequences of the suffixes.
+    let (n, m) = (expected.len(), actual.len());
+    let mut lcs = std::vec![std::vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if expected[i] == actual[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let mut out = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && expected[i] == actual[j] {
+            let _ = writeln!(out, "  {}", expected[i]);
+            i += 1;
+            j += 1;
+        } else if i < n && (j == m || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            let _ = writeln!(out, "- {}", expected[i]);
+            i += 1;
+        } else {
+            let _ = writeln!(out, "+ {}", actual[j]);
+            j += 1;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::diff_lines;
+
+    #[test]
+    fn diff() {
+        let lines = |s: &[&str]| {
+            s.iter()
+                .map(|s| s.to_string())
+                .collect::<std::vec::Vec<_>>()
+        };
+        assert_eq!(
+            diff_lines(&lines(&["a", "b", "c"]), &lines(&["a", "x", "c", "d"])),
+            "  a\n- b\n+ x\n  c\n+ d\n",
+        );
+    }
+}
@@ -0,0 +1,125 @@
+#![cfg(any(test, feature = "testutils"))]
+
+use core::marker::PhantomData;
+
+use crate::{
+    crypto::merkle::{hash_pair, hash_sorted_pair, Hasher},
+    BytesN, Env, Vec,
+};
+
+/// MerkleTree builds binary Merkle trees, with their roots and inclusion
+/// proofs, for testing contracts that verify proofs with
+/// [`crypto::merkle`][crate::crypto::merkle].
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{
+///     crypto::merkle::{self, Sha256},
+///     testutils::MerkleTree,
+///     Bytes, Env,
+/// };
+///
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+/// let env = Env::default();
+/// let leaves = ["a", "b", "c"].map(|s| {
+///     env.crypto().sha256(&Bytes::from_slice(&env, s.as_bytes()))
+/// });
+/// let tree = MerkleTree::<Sha256>::new(&env, &leaves);
+/// let proof = tree.proof(1);
+/// assert!(merkle::verify::<Sha256>(&env, &tree.root(), &leaves[1], 1, 3, &proof));
+/// # }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+pub struct MerkleTree<H: Hasher> {
+    env: Env,
+    // The levels of the tree, starting with the leaves, and ending with the
+    // level containing only the root.
+    levels: std::vec::Vec<std::vec::Vec<BytesN<32>>>,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: Hasher> MerkleTree<H> {
+    /// Builds the positional tree of the leaves, that has the root returned by
+    /// [`merkle::root`][crate::crypto::merkle::root].
+    ///
+    /// ### Panics
+    ///
+    /// If there are no leaves.
+    pub fn new(env: &Env, leaves: &[BytesN<32>]) -> Self {
+        Self::build(env, leaves, hash_pair::<H>)
+    }
+
+    /// Builds the sorted-pair tree of the leaves, that has the root returned
+    /// by [`merkle::root_sorted`][crate::crypto::merkle::root_sorted].
+    ///
+    /// ### Panics
+    ///
+    /// If there are no leaves.
+    pub fn new_sorted(env: &Env, leaves: &[BytesN<32>]) -> Self {
+        Self::build(env, leaves, hash_sorted_pair::<H>)
+    }
+
+    fn build(
+        env: &Env,
+        leaves: &[BytesN<32>],
+        hash: fn(&Env, &BytesN<32>, &BytesN<32>) -> BytesN<32>,
+    ) -> Self {
+        assert!(!leaves.is_empty(), "merkle tree has no leaves");
+        let mut levels = std::vec![leaves.to_vec()];
+        while levels.last().unwrap().len() > 1 {
+            let next = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash(env, left, right),
+                    // The last node of a level with an odd number of nodes is
+                    // promoted unchanged.
+                    [node] => node.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+        Self {
+            env: env.clone(),
+            levels,
+            _hasher: PhantomData,
+        }
+    }
+
+    /// Returns the leaves.
+    pub fn leaves(&self) -> Vec<BytesN<32>> {
+        Vec::from_slice(&self.env, &self.levels[0])
+    }
+
+    /// Returns the root.
+    pub fn root(&self) -> BytesN<32> {
+        self.levels.last().unwrap()[0].clone()
+    }
+
+    /// Returns the proof of the leaf at the index, for verifying with
+    /// [`merkle::verify`][crate::crypto::merkle::verify] or
+    /// [`merkle::verify_sorted`][crate::crypto::merkle::verify_sorted],
+    /// depending on how the tree was built.
+    ///
+    /// ### Panics
+    ///
+    /// If the index is out of bounds.
+    pub fn proof(&self, index: u32) -> Vec<BytesN<32>> {
+        let mut index = index as usize;
+        assert!(index < self.levels[0].len(), "index out of bounds");
+        let mut proof = Vec::new(&self.env);
+        for level in &self.levels[..self.levels.len() - 1] {
+            // Nodes without a sibling are promoted, and have nothing to prove.
+            if let Some(sibling) = level.get(index ^ 1) {
+                proof.push_back(sibling.clone());
+            }
+            index /= 2;
+        }
+        proof
+    }
+}
@@ -9,13 +9,21 @@ pub trait Sign<MSG> {
     fn sign(&self, m: MSG) -> Result<Self::Signature, Self::Error>;
 }
 
-// TODO: Add a Verify interface and ed25519 implementation to counter the Sign
-// interface.
+/// Verify implementations check signatures produced by [`Sign`]
+/// implementations for MSGs.
+pub trait Verify<MSG> {
+    type Signature;
+    type Error;
+    /// Verify checks that the signature is a valid signature of the MSG.
+    fn verify(&self, m: MSG, s: &Self::Signature) -> Result<(), Self::Error>;
+}
 
 pub mod ed25519 {
+    use core::fmt::Debug;
+
     use xdr::WriteXdr;
 
-    use crate::xdr;
+    use crate::{testutils::random, xdr, Address, Bytes, BytesN, Env, TryFromVal};
 
     #[derive(Debug)]
     pub enum Error<E: std::error::Error> {
@@ -75,11 +83,128 @@ pub mod ed25519 {
         }
     }
 
+    pub use super::Verify;
+
+    impl<V, M> Verify<M> for V
+    where
+        V: ed25519_dalek::Verifier<ed25519_dalek::Signature>,
+        M: TryInto<xdr::ScVal>,
+        <M as TryInto<xdr::ScVal>>::Error: std::error::Error,
+    {
+        type Error = Error<<M as TryInto<xdr::ScVal>>::Error>;
+        type Signature = [u8; 64];
+        fn verify(&self, m: M, s: &Self::Signature) -> Result<(), Self::Error> {
+            let mut buf = Vec::<u8>::new();
+            let val: xdr::ScVal = m.try_into().map_err(Self::Error::ConversionError)?;
+            val.write_xdr(&mut buf)?;
+            let sig = ed25519_dalek::Signature::from_bytes(s)?;
+            Ok(ed25519_dalek::Verifier::verify(self, &buf, &sig)?)
+        }
+    }
+
+    /// Keypair is an ed25519 keypair for signing in tests.
+    ///
+    /// Keypairs implement [`Sign`] and [`Verify`] for values that convert to
+    /// [`ScVal`][xdr::ScVal], and sign [`Bytes`] as-is for verification with
+    /// [`Crypto::ed25519_verify`][crate::crypto::Crypto::ed25519_verify].
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{testutils::ed25519::Keypair, Bytes, Env};
+    ///
+    /// # #[cfg(feature = "testutils")]
+    /// # fn main() {
+    /// let env = Env::default();
+    /// let keypair = Keypair::generate(&env);
+    /// let message = Bytes::from_slice(&env, b"hello");
+    /// let signature = keypair.sign_bytes(&message);
+    /// env.crypto()
+    ///     .ed25519_verify(&keypair.public_key(), &message, &signature);
+    /// # }
+    /// # #[cfg(not(feature = "testutils"))]
+    /// # fn main() { }
+    /// ```
+    pub struct Keypair {
+        env: Env,
+        keypair: ed25519_dalek::Keypair,
+    }
+
+    impl Debug for Keypair {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "Keypair({:?})", self.keypair.public)
+        }
+    }
+
+    impl Clone for Keypair {
+        fn clone(&self) -> Self {
+            Self::from_seed(&self.env, self.keypair.secret.as_bytes())
+        }
+    }
+
+    impl Keypair {
+        /// Generates a random keypair.
+        pub fn generate(env: &Env) -> Self {
+            Self::from_seed(env, &random())
+        }
+
+        /// Creates the keypair with the seed as its secret key.
+        pub fn from_seed(env: &Env, seed: &[u8; 32]) -> Self {
+            let secret = ed25519_dalek::SecretKey::from_bytes(seed).unwrap();
+            let public = ed25519_dalek::PublicKey::from(&secret);
+            Self {
+                env: env.clone(),
+                keypair: ed25519_dalek::Keypair { secret, public },
+            }
+        }
+
+        /// Returns the public key.
+        pub fn public_key(&self) -> BytesN<32> {
+            BytesN::from_array(&self.env, self.keypair.public.as_bytes())
+        }
+
+        /// Returns the address of the Stellar account with the public key.
+        pub fn address(&self) -> Address {
+            let account_id = xdr::AccountId(xdr::PublicKey::PublicKeyTypeEd25519(xdr::Uint256(
+                self.keypair.public.to_bytes(),
+            )));
+            Address::try_from_val(&self.env, &xdr::ScAddress::Account(account_id)).unwrap()
+        }
+
+        /// Signs the bytes as-is, returning a signature that verifies with
+        /// [`Crypto::ed25519_verify`][crate::crypto::Crypto::ed25519_verify].
+        pub fn sign_bytes(&self, message: &Bytes) -> BytesN<64> {
+            let mut buf = std::vec![0; message.len() as usize];
+            message.copy_into_slice(&mut buf);
+            let signature = ed25519_dalek::Signer::sign(&self.keypair, &buf);
+            BytesN::from_array(&self.env, &signature.to_bytes())
+        }
+    }
+
+    impl ed25519_dalek::Signer<ed25519_dalek::Signature> for Keypair {
+        fn try_sign(
+            &self,
+            msg: &[u8],
+        ) -> Result<ed25519_dalek::Signature, ed25519_dalek::SignatureError> {
+            ed25519_dalek::Signer::try_sign(&self.keypair, msg)
+        }
+    }
+
+    impl ed25519_dalek::Verifier<ed25519_dalek::Signature> for Keypair {
+        fn verify(
+            &self,
+            msg: &[u8],
+            signature: &ed25519_dalek::Signature,
+        ) -> Result<(), ed25519_dalek::SignatureError> {
+            ed25519_dalek::Verifier::verify(&self.keypair, msg, signature)
+        }
+    }
+
     #[cfg(test)]
     mod test {
         use ed25519_dalek::{Keypair, PublicKey, SecretKey};
 
-        use super::Sign;
+        use super::{Sign, Verify};
 
         #[test]
         fn sign() {
@@ -100,5 +225,15 @@ pub mod ed25519 {
                 "a9b9dfac10bc1e5c8bc565e9515e5d086e3264b71bf4daf2c7340e1d10fae86e2563fa1d639ff153559a9710dfa270a9462fe87faa0e18a7a54a8a1a6151e909",
             );
         }
+
+        #[test]
+        fn verify() {
+            let secret = SecretKey::from_bytes(&[7; 32]).unwrap();
+            let public = PublicKey::from(&secret);
+            let kp = Keypair { secret, public };
+            let sig = kp.sign(128i64).unwrap();
+            assert!(kp.verify(128i64, &sig).is_ok());
+            assert!(kp.verify(129i64, &sig).is_err());
+        }
     }
 }
@@ -0,0 +1,251 @@
+#![cfg(any(test, feature = "testutils"))]
+
+use std::rc::Rc;
+
+use crate::{
+    auth::account::Signature,
+    testutils::{ed25519::Keypair, MockAuthInvoke},
+    vec,
+    xdr::{self, WriteXdr},
+    Address, Bytes, BytesN, Env, IntoVal, RawVal, Vec,
+};
+
+/// AuthSigner produces the `signature_args` of a signed
+/// [`ContractAuth`][xdr::ContractAuth] for its signature payload.
+///
+/// Ed25519 keypairs sign for the Stellar accounts they are signers of. A
+/// single keypair signs for the account of its own public key, and a slice or
+/// array of keypairs signs for accounts that require multiple signers.
+///
+/// Closures of the form `Fn(&Env, &BytesN<32>) -> Vec<RawVal>` sign for custom
+/// account contracts, and the values they return are passed as the signatures
+/// to the account contract's `__check_auth` function.
+pub trait AuthSigner {
+    /// Returns the signature args for the signature payload.
+    fn signature_args(&self, env: &Env, payload: &BytesN<32>) -> Vec<RawVal>;
+}
+
+impl AuthSigner for Keypair {
+    fn signature_args(&self, env: &Env, payload: &BytesN<32>) -> Vec<RawVal> {
+        core::slice::from_ref(self).signature_args(env, payload)
+    }
+}
+
+impl AuthSigner for [Keypair] {
+    fn signature_args(&self, env: &Env, payload: &BytesN<32>) -> Vec<RawVal> {
+        // Accounts require signatures ordered by public key.
+        let mut keypairs = self.iter().collect::<std::vec::Vec<_>>();
+        keypairs.sort_by_key(|keypair| keypair.public_key().to_array());
+        let message: Bytes = payload.clone().into();
+        let mut signatures = Vec::<Signature>::new(env);
+        for keypair in keypairs {
+            signatures.push_back(Signature {
+                public_key: keypair.public_key(),
+                signature: keypair.sign_bytes(&message),
+            });
+        }
+        vec![env, signatures.into_val(env)]
+    }
+}
+
+impl<const N: usize> AuthSigner for [Keypair; N] {
+    fn signature_args(&self, env: &Env, payload: &BytesN<32>) -> Vec<RawVal> {
+        self[..].signature_args(env, payload)
+    }
+}
+
+impl<F> AuthSigner for F
+where
+    F: Fn(&Env, &BytesN<32>) -> Vec<RawVal>,
+{
+    fn signature_args(&self, env: &Env, payload: &BytesN<32>) -> Vec<RawVal> {
+        self(env, payload)
+    }
+}
+
+/// ContractAuthBuilder builds signed [`ContractAuth`][xdr::ContractAuth]s
+/// that authorize an address for a tree of invocations.
+///
+/// The signature payload is computed with the network ID of the ledger and,
+/// unless one is set with [`nonce`][Self::nonce], the address's current nonce
+/// for the root contract of the invocation tree.
+///
+/// Signed authorizations are set on the environment with
+/// [`Env::set_auths`], after which invocations check them as they would on
+/// the network, including calling `__check_auth` on account contracts.
+///
+/// When the address is a Stellar account, the builder creates the account in
+/// the ledger if it does not exist, with the account's key as its only signer,
+/// so that the account's signatures can be checked.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{
+///     auth::Context, contractimpl, Address, BytesN, Env, IntoVal, RawVal, Vec,
+///     testutils::{ContractAuthBuilder, MockAuthInvoke},
+/// };
+///
+/// pub struct HelloContract;
+///
+/// #[contractimpl]
+/// impl HelloContract {
+///     pub fn hello(env: Env, from: Address) {
+///         from.require_auth();
+///     }
+/// }
+///
+/// pub struct AccountContract;
+///
+/// #[contractimpl]
+/// impl AccountContract {
+///     #[allow(non_snake_case)]
+///     pub fn __check_auth(
+///         env: Env,
+///         signature_payload: BytesN<32>,
+///         signatures: Vec<BytesN<32>>,
+///         _auth_context: Vec<Context>,
+///     ) {
+///         // A real account contract would verify signatures here.
+///         assert_eq!(signatures, Vec::from_array(&env, [signature_payload]));
+///     }
+/// }
+///
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+/// let env = Env::default();
+/// let contract_id = env.register_contract(None, HelloContract);
+/// let client = HelloContractClient::new(&env, &contract_id);
+/// let account = env.register_contract(None, AccountContract);
+///
+/// let auth = ContractAuthBuilder::new(
+///     &env,
+///     &account,
+///     &MockAuthInvoke {
+///         contract: &contract_id,
+///         fn_name: "hello",
+///         args: (&account,).into_val(&env),
+///         sub_invokes: &[],
+///     },
+/// )
+/// .sign(&|env: &Env, payload: &BytesN<32>| -> Vec<RawVal> {
+///     // Sign the payload as the account contract expects.
+///     (payload.clone(),).into_val(env)
+/// });
+/// env.set_auths(&[auth]);
+/// client.hello(&account);
+/// # }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+pub struct ContractAuthBuilder {
+    env: Env,
+    address: xdr::ScAddress,
+    invocation: xdr::AuthorizedInvocation,
+    nonce: Option<u64>,
+}
+
+impl ContractAuthBuilder {
+    /// Creates a builder of authorizations for the address for the invocation
+    /// tree.
+    pub fn new(env: &Env, address: &Address, invoke: &MockAuthInvoke) -> Self {
+        let address = address.try_into().unwrap();
+        create_account_if_missing(env, &address);
+        Self {
+            env: env.clone(),
+            address,
+            invocation: invoke.into(),
+            nonce: None,
+        }
+    }
+
+    /// Creates a builder of authorizations for the address and invocation
+    /// tree of the authorization, such as those returned by
+    /// [`Env::record_auths`], using its nonce.
+    ///
+    /// ### Panics
+    ///
+    /// If the authorization has no address, as is the case for authorizations
+    /// of the invoker.
+    pub fn from_auth(env: &Env, auth: &xdr::ContractAuth) -> Self {
+        let address_with_nonce = auth
+            .address_with_nonce
+            .as_ref()
+            .expect("authorization has no address");
+        create_account_if_missing(env, &address_with_nonce.address);
+        Self {
+            env: env.clone(),
+            address: address_with_nonce.address.clone(),
+            invocation: auth.root_invocation.clone(),
+            nonce: Some(address_with_nonce.nonce),
+        }
+    }
+
+    /// Sets the nonce of the authorization, instead of using the address's
+    /// current nonce.
+    pub fn nonce(mut self, nonce: u64) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    /// Returns the signature payload of the authorization, that signers sign.
+    pub fn signature_payload(&self) -> BytesN<32> {
+        let preimage = xdr::HashIdPreimage::ContractAuth(xdr::HashIdPreimageContractAuth {
+            network_id: xdr::Hash(self.env.ledger().network_id().to_array()),
+            nonce: self.resolve_nonce(),
+            invocation: self.invocation.clone(),
+        });
+        let preimage = Bytes::from_slice(&self.env, &preimage.to_xdr().unwrap());
+        self.env.crypto().sha256(&preimage)
+    }
+
+    /// Signs the authorization with the signer.
+    pub fn sign<S: AuthSigner + ?Sized>(&self, signer: &S) -> xdr::ContractAuth {
+        let signature_args = signer.signature_args(&self.env, &self.signature_payload());
+        xdr::ContractAuth {
+            address_with_nonce: Some(xdr::AddressWithNonce {
+                address: self.address.clone(),
+                nonce: self.resolve_nonce(),
+            }),
+            root_invocation: self.invocation.clone(),
+            signature_args: signature_args.try_into().unwrap(),
+        }
+    }
+
+    /// Returns the nonce set on the builder, or the next nonce of the address
+    /// for the root contract of the invocation tree as stored in the ledger.
+    fn resolve_nonce(&self) -> u64 {
+        if let Some(nonce) = self.nonce {
+            return nonce;
+        }
+        let key = Rc::new(xdr::LedgerKey::ContractData(xdr::LedgerKeyContractData {
+            contract_id: self.invocation.contract_id.clone(),
+            key: xdr::ScVal::LedgerKeyNonce(xdr::ScNonceKey {
+                nonce_address: self.address.clone(),
+            }),
+        }));
+        let host = self.env.host();
+        host.with_mut_storage(|storage| {
+            let budget = soroban_env_host::budget::AsBudget::as_budget(host);
+            if !storage.has(&key, budget)? {
+                return Ok(0);
+            }
+            match &storage.get(&key, budget)?.data {
+                xdr::LedgerEntryData::ContractData(xdr::ContractDataEntry {
+                    val: xdr::ScVal::U64(nonce),
+                    ..
+                }) => Ok(*nonce),
+                _ => Ok(0),
+            }
+        })
+        .unwrap()
+    }
+}
+
+/// Creates the account of the address in the ledger, if the address is a
+/// Stellar account that does not exist.
+fn create_account_if_missing(env: &Env, address: &xdr::ScAddress) {
+    if let xdr::ScAddress::Account(account_id) = address {
+        env.create_account(account_id);
+    }
+}
@@ -9,8 +9,8 @@ use core::{
 };
 
 use crate::{
-    iter::{UncheckedEnumerable, UncheckedIter},
-    unwrap::UnwrapInfallible,
+    iter::{CollectIn, FromIteratorIn, UncheckedEnumerable, UncheckedIter},
+    unwrap::{UnwrapInfallible, UnwrapOptimized},
 };
 
 use super::{
@@ -584,6 +584,190 @@ where
         unsafe { Self::unchecked_new(env.clone(), obj) }
     }
 
+    /// Shortens the [Vec] to the length, keeping the first `len` elements.
+    ///
+    /// If the length is greater than or equal to the [Vec]'s current length,
+    /// this has no effect.
+    #[inline(always)]
+    pub fn truncate(&mut self, len: u32) {
+        if len < self.len() {
+            *self = self.slice(..len);
+        }
+    }
+
+    /// Splits the [Vec] into two at the index, returning the elements from the
+    /// index onwards, and keeping the elements before the index.
+    ///
+    /// ### Panics
+    ///
+    /// If the index is greater than the [Vec]'s length.
+    #[must_use]
+    pub fn split_off(&mut self, at: u32) -> Self {
+        let other = self.slice(at..);
+        *self = self.slice(..at);
+        other
+    }
+
+    /// Swaps the elements at the indexes.
+    ///
+    /// ### Panics
+    ///
+    /// If either index is out of bounds.
+    pub fn swap(&mut self, i: u32, j: u32) {
+        let env = self.env();
+        let a = env.vec_get(self.obj, i.into()).unwrap_infallible();
+        let b = env.vec_get(self.obj, j.into()).unwrap_infallible();
+        let obj = env.vec_put(self.obj, i.into(), b).unwrap_infallible();
+        self.obj = env.vec_put(obj, j.into(), a).unwrap_infallible();
+    }
+
+    /// Reverses the order of the elements in the [Vec].
+    pub fn reverse(&mut self) {
+        // Elements are reversed in chunks in guest memory, so that the
+        // reversed vec is built with a host call per chunk rather than per
+        // element.
+        const CHUNK: u32 = 16;
+        let env = self.env();
+        let mut buf = [RawVal::VOID.to_raw(); CHUNK as usize];
+        let mut reversed = env.vec_new(().into()).unwrap_infallible();
+        let mut end = self.len();
+        while end > 0 {
+            let start = end.saturating_sub(CHUNK);
+            let chunk = &mut buf[..(end - start) as usize];
+            let slice = env
+                .vec_slice(self.obj, start.into(), end.into())
+                .unwrap_infallible();
+            env.vec_unpack_to_slice(slice, chunk).unwrap_infallible();
+            chunk.reverse();
+            let chunk = env.vec_new_from_slice(chunk).unwrap_infallible();
+            reversed = env.vec_append(reversed, chunk).unwrap_infallible();
+            end = start;
+        }
+        self.obj = reversed;
+    }
+
+    /// Sorts the [Vec] in ascending order.
+    ///
+    /// Elements are ordered as the environment orders their values, which is
+    /// the ordering that [Vec::binary_search] expects, and for integers and
+    /// other SDK types is the same as their [Ord] implementation. Equal
+    /// elements are indistinguishable, so the sort is stable.
+    ///
+    /// The sort is a merge sort, that compares elements `O(n log n)` times,
+    /// with a host call for each comparison. Elements are read from and
+    /// written to the environment a chunk at a time.
+    pub fn sort(&mut self) {
+        self.obj = merge_sort(self.env(), self.obj);
+    }
+
+    /// Sorts the [Vec] in ascending order of the keys that the function
+    /// returns for each element.
+    ///
+    /// Keys are ordered as the environment orders their values, the same as
+    /// the elements are ordered by [Vec::sort]. The sort is stable, and
+    /// elements with equal keys keep their order.
+    ///
+    /// The function is called once for each element. Each key is paired with
+    /// the index of its element, and the pairs are sorted with [Vec::sort], so
+    /// the cost of the sort grows as `O(n log n)`.
+    ///
+    /// ### Panics
+    ///
+    /// If an element cannot be converted to `T`.
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: IntoVal<Env, RawVal>,
+        T::Error: Debug,
+    {
+        let env = self.env();
+        let len = self.len();
+
+        // Pair each key with the index of its element, so that pairs with
+        // equal keys are ordered by index, which keeps the sort stable.
+        let mut keyed = RawVecBuilder::new(env);
+        let elements = RawVecReader::new(env, self.obj, 0, len);
+        for (i, val) in (0..len).zip(elements) {
+            let key = f(&T::try_from_val(env, &val).unwrap()).into_val(env);
+            keyed.push((key, i).into_val(env));
+        }
+        let keyed = merge_sort(env, keyed.finish());
+
+        let mut sorted = RawVecBuilder::new(env);
+        for pair in RawVecReader::new(env, keyed, 0, len) {
+            let (_, i): (RawVal, u32) = pair.try_into_val(env).unwrap_optimized();
+            sorted.push(env.vec_get(self.obj, i.into()).unwrap_infallible());
+        }
+        self.obj = sorted.finish();
+    }
+
+    /// Removes consecutive repeated elements, so that if the [Vec] is sorted
+    /// each element occurs only once.
+    pub fn dedup(&mut self) {
+        let env = self.env().clone();
+        let mut prev: Option<RawVal> = None;
+        self.retain_raw(|val| {
+            let repeated = prev.is_some_and(|prev| env.obj_cmp(prev, val).unwrap_infallible() == 0);
+            prev = Some(val);
+            !repeated
+        });
+    }
+
+    /// Retains only the elements for which the function returns true,
+    /// removing all other elements and keeping the order of the retained
+    /// elements.
+    ///
+    /// The function is called once for each element, in order.
+    ///
+    /// ### Panics
+    ///
+    /// If an element cannot be converted to `T`.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+        T::Error: Debug,
+    {
+        let env = self.env().clone();
+        self.retain_raw(|val| f(&T::try_from_val(&env, &val).unwrap()));
+    }
+
+    /// Retains only the values for which the function returns true.
+    ///
+    /// Each value is read from the host to be tested, and the runs of retained
+    /// values between removed values are appended to the result as slices,
+    /// rather than pushing each retained value. The [Vec] is left unchanged if
+    /// no values are removed.
+    fn retain_raw(&mut self, mut keep: impl FnMut(RawVal) -> bool) {
+        let env = self.env();
+        let len = self.len();
+        let mut retained = env.vec_new(().into()).unwrap_infallible();
+        // The start of the run of values that are retained and yet to be
+        // appended.
+        let mut start = 0;
+        for i in 0..len {
+            let val = env.vec_get(self.obj, i.into()).unwrap_infallible();
+            if !keep(val) {
+                if start < i {
+                    let run = env
+                        .vec_slice(self.obj, start.into(), i.into())
+                        .unwrap_infallible();
+                    retained = env.vec_append(retained, run).unwrap_infallible();
+                }
+                start = i + 1;
+            }
+        }
+        if start == 0 {
+            return;
+        }
+        if start < len {
+            let run = env
+                .vec_slice(self.obj, start.into(), len.into())
+                .unwrap_infallible();
+            retained = env.vec_append(retained, run).unwrap_infallible();
+        }
+        self.obj = retained;
+    }
+
     pub fn iter(&self) -> VecIter<T>
     where
         T: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal> + Clone,
@@ -608,6 +792,55 @@ where
     {
         self.into_iter().unchecked()
     }
+
+    /// Returns a [Vec] of the results of calling the function on each
+    /// element, in order.
+    ///
+    /// ### Panics
+    ///
+    /// If an element cannot be converted to `T`.
+    #[must_use]
+    pub fn map<U, F>(&self, f: F) -> Vec<U>
+    where
+        T: Clone,
+        T::Error: Debug,
+        U: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+        F: FnMut(T) -> U,
+    {
+        self.iter_unchecked().map(f).collect_in(self.env())
+    }
+
+    /// Returns a [Vec] of the elements for which the function returns true,
+    /// in order.
+    ///
+    /// ### Panics
+    ///
+    /// If an element cannot be converted to `T`.
+    #[must_use]
+    pub fn filter<F>(&self, f: F) -> Vec<T>
+    where
+        T: Clone,
+        T::Error: Debug,
+        F: FnMut(&T) -> bool,
+    {
+        self.iter_unchecked().filter(f).collect_in(self.env())
+    }
+
+    /// Folds the elements into an accumulator, by calling the function with
+    /// the accumulator and each element in order, and returns the final
+    /// accumulator.
+    ///
+    /// ### Panics
+    ///
+    /// If an element cannot be converted to `T`.
+    pub fn fold<B, F>(&self, init: B, f: F) -> B
+    where
+        T: Clone,
+        T::Error: Debug,
+        F: FnMut(B, T) -> B,
+    {
+        self.iter_unchecked().fold(init, f)
+    }
 }
 
 impl<T> Vec<T>
@@ -674,6 +907,201 @@ where
     }
 }
 
+// The number of values that sorting reads and writes with each host call.
+const SORT_CHUNK: u32 = 16;
+
+/// Reads the values in a range of a host vec, unpacking them into guest memory
+/// a chunk at a time.
+struct RawVecReader<'a> {
+    env: &'a Env,
+    obj: VecObject,
+    next: u32,
+    end: u32,
+    buf: [RawVal; SORT_CHUNK as usize],
+    pos: usize,
+    len: usize,
+}
+
+impl<'a> RawVecReader<'a> {
+    fn new(env: &'a Env, obj: VecObject, start: u32, end: u32) -> Self {
+        RawVecReader {
+            env,
+            obj,
+            next: start,
+            end,
+            buf: [RawVal::VOID.to_raw(); SORT_CHUNK as usize],
+            pos: 0,
+            len: 0,
+        }
+    }
+
+    fn peek(&mut self) -> Option<RawVal> {
+        if self.pos == self.len {
+            if self.next == self.end {
+                return None;
+            }
+            let end = self.end.min(self.next.saturating_add(SORT_CHUNK));
+            let chunk = &mut self.buf[..(end - self.next) as usize];
+            let slice = self
+                .env
+                .vec_slice(self.obj, self.next.into(), end.into())
+                .unwrap_infallible();
+            self.env
+                .vec_unpack_to_slice(slice, chunk)
+                .unwrap_infallible();
+            self.pos = 0;
+            self.len = chunk.len();
+            self.next = end;
+        }
+        Some(self.buf[self.pos])
+    }
+}
+
+impl Iterator for RawVecReader<'_> {
+    type Item = RawVal;
+
+    fn next(&mut self) -> Option<RawVal> {
+        let val = self.peek()?;
+        self.pos += 1;
+        Some(val)
+    }
+}
+
+/// Builds a host vec from values pushed in order.
+///
+/// Values are buffered in guest memory and each full buffer becomes a host
+/// vec. Host vecs are immutable, and appending copies both vecs, so the vecs
+/// are appended like the digits of a binary counter: a vec is only appended
+/// to the vec before it once they are the same length. Each value is then
+/// copied a number of times that grows logarithmically with the length of the
+/// vec, rather than linearly as when appending each chunk to one vec.
+struct RawVecBuilder<'a> {
+    env: &'a Env,
+    buf: [RawVal; SORT_CHUNK as usize],
+    len: usize,
+    // Vecs, with their lengths, that concatenated are the values pushed
+    // before those in the buffer. Lengths strictly decrease.
+    parts: [Option<(VecObject, u32)>; 32],
+    count: usize,
+}
+
+impl<'a> RawVecBuilder<'a> {
+    fn new(env: &'a Env) -> Self {
+        RawVecBuilder {
+            env,
+            buf: [RawVal::VOID.to_raw(); SORT_CHUNK as usize],
+            len: 0,
+            parts: [None; 32],
+            count: 0,
+        }
+    }
+
+    fn push(&mut self, val: RawVal) {
+        self.buf[self.len] = val;
+        self.len += 1;
+        if self.len == self.buf.len() {
+            self.flush();
+        }
+    }
+
+    fn flush(&mut self) {
+        if self.len == 0 {
+            return;
+        }
+        let env = self.env;
+        let mut obj = env
+            .vec_new_from_slice(&self.buf[..self.len])
+            .unwrap_infallible();
+        let mut len = self.len as u32;
+        self.len = 0;
+        while let Some((prev, prev_len)) = self.count.checked_sub(1).and_then(|i| self.parts[i]) {
+            if prev_len > len {
+                break;
+            }
+            obj = env.vec_append(prev, obj).unwrap_infallible();
+            len += prev_len;
+            self.count -= 1;
+        }
+        self.parts[self.count] = Some((obj, len));
+        self.count += 1;
+    }
+
+    fn finish(mut self) -> VecObject {
+        self.flush();
+        let env = self.env;
+        let mut parts = self.parts[..self.count].iter().rev().flatten();
+        let Some(&(mut obj, _)) = parts.next() else {
+            return env.vec_new(().into()).unwrap_infallible();
+        };
+        for &(prev, _) in parts {
+            obj = env.vec_append(prev, obj).unwrap_infallible();
+        }
+        obj
+    }
+}
+
+/// Sorts the values of the host vec in the order that the environment orders
+/// them, with a stable bottom-up merge sort, returning the sorted vec.
+fn merge_sort(env: &Env, mut obj: VecObject) -> VecObject {
+    let len: u32 = env.vec_len(obj).unwrap_infallible().into();
+
+    // Sort each chunk in guest memory, so that the runs that are merged
+    // start out as long as a chunk.
+    let mut buf = [RawVal::VOID.to_raw(); SORT_CHUNK as usize];
+    let mut runs = RawVecBuilder::new(env);
+    let mut start = 0;
+    while start < len {
+        let end = len.min(start.saturating_add(SORT_CHUNK));
+        let chunk = &mut buf[..(end - start) as usize];
+        let slice = env
+            .vec_slice(obj, start.into(), end.into())
+            .unwrap_infallible();
+        env.vec_unpack_to_slice(slice, chunk).unwrap_infallible();
+        for i in 1..chunk.len() {
+            let mut j = i;
+            while j > 0 && env.obj_cmp(chunk[j - 1], chunk[j]).unwrap_infallible() > 0 {
+                chunk.swap(j - 1, j);
+                j -= 1;
+            }
+        }
+        for &val in chunk.iter() {
+            runs.push(val);
+        }
+        start = end;
+    }
+    obj = runs.finish();
+
+    // Merge each pair of adjacent runs, doubling the length of the runs
+    // with each pass. Values of the first run are taken before equal
+    // values of the second, so that the sort is stable.
+    let mut width = SORT_CHUNK;
+    while width < len {
+        let mut merged = RawVecBuilder::new(env);
+        let mut low = 0;
+        while low < len {
+            let mid = len.min(low.saturating_add(width));
+            let high = len.min(mid.saturating_add(width));
+            let mut a = RawVecReader::new(env, obj, low, mid);
+            let mut b = RawVecReader::new(env, obj, mid, high);
+            loop {
+                let next = match (a.peek(), b.peek()) {
+                    (Some(x), Some(y)) if env.obj_cmp(y, x).unwrap_infallible() < 0 => b.next(),
+                    (Some(_), _) => a.next(),
+                    (None, _) => b.next(),
+                };
+                match next {
+                    Some(val) => merged.push(val),
+                    None => break,
+                }
+            }
+            low = high;
+        }
+        obj = merged.finish();
+        width = width.saturating_mul(2);
+    }
+    obj
+}
+
 impl<T> Vec<Vec<T>>
 where
     T: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
@@ -701,6 +1129,33 @@ where
     }
 }
 
+impl<T> FromIteratorIn<T> for Vec<T>
+where
+    T: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+    fn from_iter_in<I: IntoIterator<Item = T>>(env: &Env, iter: I) -> Self {
+        // Items are converted into a buffer in guest memory, so that the vec
+        // is built with a host call per chunk of items rather than per item.
+        let mut obj = env.vec_new(().into()).unwrap_infallible();
+        let mut buf = [RawVal::VOID.to_raw(); 16];
+        let mut iter = iter.into_iter();
+        loop {
+            let len = buf
+                .iter_mut()
+                .zip(&mut iter)
+                .map(|(slot, item)| *slot = item.into_val(env))
+                .count();
+            if len > 0 {
+                let chunk = env.vec_new_from_slice(&buf[..len]).unwrap_infallible();
+                obj = env.vec_append(obj, chunk).unwrap_infallible();
+            }
+            if len < buf.len() {
+                return unsafe { Self::unchecked_new(env.clone(), obj) };
+            }
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct VecIter<T>(Vec<T>);
 
@@ -1099,4 +1554,195 @@ mod test {
         assert_eq!(v, vec![&env]);
         assert_eq!(v.pop_back(), None);
     }
+
+    #[test]
+    fn truncate_and_split_off() {
+        let env = Env::default();
+
+        let mut v = vec![&env, 1, 2, 3, 4];
+        v.truncate(5);
+        assert_eq!(v, vec![&env, 1, 2, 3, 4]);
+        v.truncate(3);
+        assert_eq!(v, vec![&env, 1, 2, 3]);
+
+        let tail = v.split_off(1);
+        assert_eq!(v, vec![&env, 1]);
+        assert_eq!(tail, vec![&env, 2, 3]);
+        let tail = v.split_off(1);
+        assert_eq!(v, vec![&env, 1]);
+        assert_eq!(tail, vec![&env]);
+
+        v.truncate(0);
+        assert_eq!(v, vec![&env]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_off_out_of_bounds() {
+        let env = Env::default();
+        let mut v = vec![&env, 1, 2];
+        let _ = v.split_off(3);
+    }
+
+    #[test]
+    fn swap_and_reverse() {
+        let env = Env::default();
+
+        let mut v = vec![&env, 1, 2, 3];
+        v.swap(0, 2);
+        assert_eq!(v, vec![&env, 3, 2, 1]);
+        v.swap(1, 1);
+        assert_eq!(v, vec![&env, 3, 2, 1]);
+        v.reverse();
+        assert_eq!(v, vec![&env, 1, 2, 3]);
+
+        // Vecs longer than the chunks that are reversed at a time.
+        for len in [0, 1, 15, 16, 17, 40] {
+            let mut v = Vec::<u32>::new(&env);
+            let mut expected = Vec::<u32>::new(&env);
+            for i in 0..len {
+                v.push_back(i);
+                expected.push_front(i);
+            }
+            v.reverse();
+            assert_eq!(v, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn swap_out_of_bounds() {
+        let env = Env::default();
+        let mut v = vec![&env, 1, 2];
+        v.swap(0, 2);
+    }
+
+    #[test]
+    fn sort() {
+        let env = Env::default();
+
+        let mut v = vec![&env, 5, -3, 9, 0, 5, -7, 2];
+        v.sort();
+        assert_eq!(v, vec![&env, -7, -3, 0, 2, 5, 5, 9]);
+        assert_eq!(v.binary_search(2), Ok(3));
+
+        let mut v = Vec::<i32>::new(&env);
+        v.sort();
+        assert_eq!(v, vec![&env]);
+
+        // Vecs longer than a chunk are sorted by merging runs.
+        let vals: std::vec::Vec<u32> = (0..100u32).map(|i| (i * 37) % 101).collect();
+        let mut v = Vec::from_slice(&env, &vals);
+        v.sort();
+        let mut expect = vals.clone();
+        expect.sort();
+        assert_eq!(v, Vec::from_slice(&env, &expect));
+    }
+
+    #[test]
+    fn sort_by_key() {
+        let env = Env::default();
+
+        let mut v = vec![&env, (1u32, 3i32), (2, 1), (3, 3), (4, -2), (5, 1)];
+        v.sort_by_key(|(_, key)| *key);
+        assert_eq!(v, vec![&env, (4, -2), (2, 1), (5, 1), (1, 3), (3, 3)]);
+
+        let mut v = vec![&env, 10u32, 21, 30, 41, 50];
+        v.sort_by_key(|x| x % 2 == 1);
+        assert_eq!(v, vec![&env, 10, 30, 50, 21, 41]);
+
+        // Elements with equal keys keep their order across merged runs, and
+        // the function is called once for each element.
+        let mut v = (0..100u32).collect_in::<Vec<_>>(&env);
+        let mut calls = 0;
+        v.sort_by_key(|x| {
+            calls += 1;
+            x % 3
+        });
+        assert_eq!(calls, 100);
+        let expect = (0..100u32)
+            .filter(|x| x % 3 == 0)
+            .chain((0..100).filter(|x| x % 3 == 1))
+            .chain((0..100).filter(|x| x % 3 == 2));
+        assert_eq!(v, expect.collect_in::<Vec<_>>(&env));
+    }
+
+    #[test]
+    fn dedup_and_retain() {
+        let env = Env::default();
+
+        let mut v = vec![&env, 1, 1, 2, 3, 3, 3, 1, 4, 4];
+        v.dedup();
+        assert_eq!(v, vec![&env, 1, 2, 3, 1, 4]);
+        v.dedup();
+        assert_eq!(v, vec![&env, 1, 2, 3, 1, 4]);
+
+        let mut v = vec![&env, 1, 2, 3, 4, 5, 6, 7];
+        v.retain(|x| x % 3 != 0);
+        assert_eq!(v, vec![&env, 1, 2, 4, 5, 7]);
+        v.retain(|x| *x > 4);
+        assert_eq!(v, vec![&env, 5, 7]);
+        v.retain(|_| true);
+        assert_eq!(v, vec![&env, 5, 7]);
+        v.retain(|_| false);
+        assert_eq!(v, vec![&env]);
+
+        // The function is called for each element in order.
+        let mut v = vec![&env, 1, 2, 3];
+        let mut seen = Vec::new(&env);
+        v.retain(|x| {
+            seen.push_back(*x);
+            true
+        });
+        assert_eq!(seen, vec![&env, 1, 2, 3]);
+    }
+
+    #[test]
+    fn collect_in() {
+        use crate::iter::CollectIn;
+        let env = Env::default();
+
+        let v: Vec<u32> = core::iter::empty().collect_in(&env);
+        assert_eq!(v, vec![&env]);
+
+        // Longer than the chunks that are converted at a time.
+        for len in [1u32, 16, 17, 40] {
+            let v: Vec<u32> = (0..len).collect_in(&env);
+            let mut expected = Vec::new(&env);
+            for i in 0..len {
+                expected.push_back(i);
+            }
+            assert_eq!(v, expected);
+        }
+
+        let v = vec![&env, 1i32, 2, 3];
+        let v: Vec<i64> = v.iter_unchecked().rev().map(i64::from).collect_in(&env);
+        assert_eq!(v, vec![&env, 3, 2, 1]);
+    }
+
+    #[test]
+    fn map_filter_fold() {
+        let env = Env::default();
+
+        let v = vec![&env, 1u32, 2, 3, 4, 5];
+        assert_eq!(v.map(|x| x * x), vec![&env, 1, 4, 9, 16, 25]);
+        assert_eq!(
+            v.map(|x| x % 2 == 0),
+            vec![&env, false, true, false, true, false]
+        );
+        assert_eq!(v.filter(|x| x % 2 == 1), vec![&env, 1, 3, 5]);
+        assert_eq!(v.filter(|_| false), vec![&env]);
+        assert_eq!(v.fold(0, |acc, x| acc + x), 15);
+        assert_eq!(
+            v.fold(Vec::new(&env), |mut acc, x| {
+                acc.push_front(x);
+                acc
+            }),
+            vec![&env, 5, 4, 3, 2, 1]
+        );
+
+        let v = Vec::<u32>::new(&env);
+        assert_eq!(v.map(|x| x + 1), vec![&env]);
+        assert_eq!(v.fold(7, |acc, x| acc + x), 7);
+    }
 }
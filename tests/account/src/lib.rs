@@ -0,0 +1,278 @@
+#![no_std]
+use soroban_sdk::{
+    auth::{
+        account::{AccountError, Multisig, Signature},
+        Context,
+    },
+    contractimpl, contracttype, BytesN, Env, Map, Symbol, TryFromVal, Vec,
+};
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Multisig,
+    TransferLimit,
+}
+
+/// Contract is an account with weighted ed25519 signers, that requires all
+/// signers to authorize transfers above a limit, and never authorizes
+/// allowances.
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn init(env: Env, signers: Map<BytesN<32>, u32>, threshold: u32, transfer_limit: i128) {
+        if env.storage().has(&DataKey::Multisig) {
+            panic!("account is already initialized");
+        }
+        env.storage()
+            .set(&DataKey::Multisig, &Multisig { signers, threshold });
+        env.storage().set(&DataKey::TransferLimit, &transfer_limit);
+    }
+
+    #[allow(non_snake_case)]
+    pub fn __check_auth(
+        env: Env,
+        signature_payload: BytesN<32>,
+        signatures: Vec<Signature>,
+        auth_context: Vec<Context>,
+    ) -> Result<(), AccountError> {
+        let multisig: Multisig = env.storage().get(&DataKey::Multisig).unwrap();
+        let transfer_limit: i128 = env.storage().get(&DataKey::TransferLimit).unwrap();
+        let total_weight = multisig.total_weight();
+        let policy = |env: &Env, context: &Context, threshold: u32| {
+            if context.fn_name == Symbol::short("transfer") {
+                // Transfers are (from, to, amount). Contexts with other args
+                // are denied.
+                let amount = i128::try_from_val(env, &context.args.get(2)?.ok()?).ok()?;
+                if amount > transfer_limit {
+                    return Some(total_weight);
+                }
+            } else if context.fn_name == Symbol::new(env, "increase_allowance") {
+                return None;
+            }
+            Some(threshold)
+        };
+        multisig.check_auth_with_policy(
+            &env,
+            &signature_payload,
+            &signatures,
+            &auth_context,
+            &policy,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+    use soroban_sdk::{
+        testutils::{ed25519::Keypair, Address as _, BytesN as _},
+        vec, Address, IntoVal, RawVal,
+    };
+
+    struct Setup {
+        env: Env,
+        account: BytesN<32>,
+        keypairs: std::vec::Vec<Keypair>,
+        payload: BytesN<32>,
+    }
+
+    /// Registers an account with signers of the weights, ordered by public
+    /// key.
+    fn setup(weights: &[u32], threshold: u32, transfer_limit: i128) -> Setup {
+        let env = Env::default();
+        let mut keypairs: std::vec::Vec<_> =
+            weights.iter().map(|_| Keypair::generate(&env)).collect();
+        keypairs.sort_by_key(|keypair| keypair.public_key().to_array());
+        let mut signers = Map::new(&env);
+        for (keypair, weight) in keypairs.iter().zip(weights) {
+            signers.set(keypair.public_key(), *weight);
+        }
+        let account = env.register_contract(None, Contract);
+        ContractClient::new(&env, &account).init(&signers, &threshold, &transfer_limit);
+        let payload = BytesN::random(&env);
+        Setup {
+            account: account.contract_id(),
+            env,
+            keypairs,
+            payload,
+        }
+    }
+
+    impl Setup {
+        fn sign(&self, signers: &[usize]) -> Vec<RawVal> {
+            let mut signatures = Vec::new(&self.env);
+            for i in signers {
+                let keypair = &self.keypairs[*i];
+                let signature = Signature {
+                    public_key: keypair.public_key(),
+                    signature: keypair.sign_bytes(&self.payload.clone().into()),
+                };
+                signatures.push_back(signature.into_val(&self.env));
+            }
+            signatures
+        }
+
+        fn check_auth(
+            &self,
+            signers: &[usize],
+            contexts: &[Context],
+        ) -> Result<(), Result<AccountError, soroban_sdk::Error>> {
+            let mut auth_context = Vec::new(&self.env);
+            for context in contexts {
+                auth_context.push_back(context.clone());
+            }
+            self.env.try_invoke_contract_check_auth::<AccountError>(
+                &self.account,
+                &self.payload,
+                &self.sign(signers),
+                &auth_context,
+            )
+        }
+
+        fn context(&self, fn_name: &str, amount: i128) -> Context {
+            Context {
+                contract: BytesN::random(&self.env),
+                fn_name: Symbol::new(&self.env, fn_name),
+                args: (
+                    Address::random(&self.env),
+                    Address::random(&self.env),
+                    amount,
+                )
+                    .into_val(&self.env),
+            }
+        }
+    }
+
+    #[test]
+    fn test_threshold() {
+        let s = setup(&[1, 1, 1], 2, 1000);
+        let transfer = s.context("transfer", 100);
+        assert_eq!(
+            s.check_auth(&[0], &[transfer.clone()]),
+            Err(Ok(AccountError::InsufficientWeight))
+        );
+        assert_eq!(s.check_auth(&[0, 1], &[transfer.clone()]), Ok(()));
+        assert_eq!(s.check_auth(&[1, 2], &[transfer.clone()]), Ok(()));
+        assert_eq!(s.check_auth(&[0, 1, 2], &[transfer]), Ok(()));
+    }
+
+    #[test]
+    fn test_weighted_signers() {
+        let s = setup(&[2, 1, 1], 2, 1000);
+        let transfer = s.context("transfer", 100);
+        assert_eq!(s.check_auth(&[0], &[transfer.clone()]), Ok(()));
+        assert_eq!(
+            s.check_auth(&[1], &[transfer.clone()]),
+            Err(Ok(AccountError::InsufficientWeight))
+        );
+        assert_eq!(s.check_auth(&[1, 2], &[transfer]), Ok(()));
+    }
+
+    #[test]
+    fn test_signatures_must_be_ordered_and_unique() {
+        let s = setup(&[1, 1, 1], 2, 1000);
+        let transfer = s.context("transfer", 100);
+        assert_eq!(
+            s.check_auth(&[1, 0], &[transfer.clone()]),
+            Err(Ok(AccountError::SignaturesNotOrdered))
+        );
+        assert_eq!(
+            s.check_auth(&[0, 0], &[transfer]),
+            Err(Ok(AccountError::SignaturesNotOrdered))
+        );
+    }
+
+    #[test]
+    fn test_unknown_signer() {
+        let s = setup(&[1, 1, 1], 2, 1000);
+        let other = Keypair::generate(&s.env);
+        let signature = Signature {
+            public_key: other.public_key(),
+            signature: other.sign_bytes(&s.payload.clone().into()),
+        };
+        let res = s.env.try_invoke_contract_check_auth::<AccountError>(
+            &s.account,
+            &s.payload,
+            &vec![&s.env, signature.into_val(&s.env)],
+            &vec![&s.env, s.context("transfer", 100)],
+        );
+        assert_eq!(res, Err(Ok(AccountError::UnknownSigner)));
+    }
+
+    #[test]
+    fn test_invalid_signature() {
+        let s = setup(&[1, 1, 1], 1, 1000);
+        let keypair = &s.keypairs[0];
+        let signature = Signature {
+            public_key: keypair.public_key(),
+            signature: keypair.sign_bytes(&BytesN::<32>::random(&s.env).into()),
+        };
+        let res = s.env.try_invoke_contract_check_auth::<AccountError>(
+            &s.account,
+            &s.payload,
+            &vec![&s.env, signature.into_val(&s.env)],
+            &vec![&s.env, s.context("transfer", 100)],
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_transfer_limit_policy() {
+        let s = setup(&[1, 1, 1], 2, 1000);
+        assert_eq!(
+            s.check_auth(&[0, 1], &[s.context("transfer", 1000)]),
+            Ok(())
+        );
+        assert_eq!(
+            s.check_auth(&[0, 1], &[s.context("transfer", 1001)]),
+            Err(Ok(AccountError::InsufficientWeight))
+        );
+        assert_eq!(
+            s.check_auth(&[0, 1, 2], &[s.context("transfer", 1001)]),
+            Ok(())
+        );
+
+        // Every context must be authorized by the signers.
+        assert_eq!(
+            s.check_auth(
+                &[0, 1],
+                &[s.context("transfer", 10), s.context("transfer", 5000)]
+            ),
+            Err(Ok(AccountError::InsufficientWeight))
+        );
+    }
+
+    #[test]
+    fn test_denied_context() {
+        let s = setup(&[1, 1, 1], 2, 1000);
+        assert_eq!(
+            s.check_auth(&[0, 1, 2], &[s.context("increase_allowance", 10)]),
+            Err(Ok(AccountError::ContextDenied))
+        );
+        assert_eq!(s.check_auth(&[0, 1], &[s.context("mint", 10)]), Ok(()));
+    }
+
+    #[test]
+    fn test_zero_threshold_denies() {
+        let s = setup(&[1, 1], 0, 1000);
+        assert_eq!(
+            s.check_auth(&[], &[s.context("mint", 10)]),
+            Err(Ok(AccountError::ContextDenied))
+        );
+        assert_eq!(
+            s.check_auth(&[0, 1], &[s.context("mint", 10)]),
+            Err(Ok(AccountError::ContextDenied))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "multisig threshold must be greater than zero")]
+    fn test_new_rejects_zero_threshold() {
+        let env = Env::default();
+        Multisig::new(&env, 0);
+    }
+}
@@ -0,0 +1,67 @@
+#![no_std]
+use soroban_sdk::{contractimpl, Address, Env, Symbol};
+
+const ADMIN: Symbol = Symbol::short("ADMIN");
+
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().set(&ADMIN, &admin);
+    }
+
+    pub fn admin(env: Env) -> Address {
+        env.storage().get(&ADMIN).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use soroban_sdk::{
+        testutils::{Address as _, BytesN as _},
+        Address, BytesN, Env, IntoVal, Symbol,
+    };
+
+    use crate::{Contract, ContractClient};
+
+    #[test]
+    fn test_constructor() {
+        let e = Env::default();
+        let admin = Address::random(&e);
+        let contract_id = e.register_contract_with_constructor(None, Contract, (admin.clone(),));
+        let client = ContractClient::new(&e, &contract_id);
+
+        assert_eq!(client.admin(), admin);
+    }
+
+    #[test]
+    fn test_constructor_cannot_be_invoked_by_others() {
+        let e = Env::default();
+        let admin = Address::random(&e);
+        let contract_id = e.register_contract_with_constructor(None, Contract, (admin.clone(),));
+
+        let other = Address::random(&e);
+        let res = e.try_invoke_contract::<(), soroban_sdk::Error>(
+            &contract_id,
+            &Symbol::new(&e, "__constructor"),
+            (BytesN::<32>::random(&e), other).into_val(&e),
+        );
+        assert!(res.is_err());
+        assert_eq!(ContractClient::new(&e, &contract_id).admin(), admin);
+    }
+
+    #[test]
+    fn test_constructor_cannot_be_invoked_without_deploy() {
+        let e = Env::default();
+        let contract_id = e.register_contract(None, Contract);
+
+        let admin = Address::random(&e);
+        let res = e.try_invoke_contract::<(), soroban_sdk::Error>(
+            &contract_id,
+            &Symbol::new(&e, "__constructor"),
+            (BytesN::<32>::random(&e), admin).into_val(&e),
+        );
+        assert!(res.is_err());
+    }
+}
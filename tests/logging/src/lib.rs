@@ -1,5 +1,5 @@
 #![no_std]
-use soroban_sdk::{contractimpl, log, Env, Symbol};
+use soroban_sdk::{contractimpl, log, vec, Address, Bytes, Env, Symbol};
 
 pub struct Contract;
 
@@ -23,16 +23,44 @@ impl Contract {
             Symbol::short("two"),
         );
     }
+
+    pub fn levels(env: Env) {
+        log!(&env, Error: "error");
+        log!(&env, Warn: "warn", 1u32);
+        log!(&env, Info: "info", 1u32; b = 2u32, a = Symbol::short("a"));
+        log!(&env, Trace: "trace"; a = 1u32,);
+        log!(&env, "debug"; a = 1u32);
+    }
+
+    pub fn render(env: Env, to: Address) {
+        log!(
+            &env,
+            Info: "sent {} to {}",
+            10i128,
+            to,
+            vec![&env, Symbol::short("a"), Symbol::short("b")];
+            memo = Bytes::from_array(&env, &[0xab, 0x01]),
+        );
+    }
 }
 
 #[cfg(test)]
 mod test {
     extern crate std;
 
-    use soroban_sdk::{testutils::Logger, Env};
+    use soroban_sdk::{
+        logging::Level,
+        testutils::{Address as _, LogRecord, Logger},
+        xdr::{ScSymbol, ScVal},
+        Address, Env,
+    };
 
     use crate::{Contract, ContractClient};
 
+    fn sym(s: &str) -> ScVal {
+        ScVal::Symbol(ScSymbol(s.try_into().unwrap()))
+    }
+
     #[test]
     fn test_logging() {
         let env = Env::default();
@@ -44,19 +72,135 @@ mod test {
         env.logger().print();
 
         if cfg!(debug_assertions) {
-            let pats = std::vec![
-                "\"none\"",
-                "\"none\"",
-                "[\"one:\", one]",
-                "[\"one:\", one]",
-                "[\"one and two:\", one, two]",
-                "[\"one and two:\", one, two]"
+            let expected = [
+                ("none", std::vec![]),
+                ("none", std::vec![]),
+                ("one:", std::vec![sym("one")]),
+                ("one:", std::vec![sym("one")]),
+                ("one and two:", std::vec![sym("one"), sym("two")]),
+                ("one and two:", std::vec![sym("one"), sym("two")]),
             ];
-            for (msg, pat) in env.logger().all().iter().zip(pats.iter()) {
-                assert!(msg.contains(pat));
+            let all = env.logger().all();
+            assert_eq!(all.len(), expected.len());
+            for (record, (msg, args)) in all.into_iter().zip(expected) {
+                assert_eq!(
+                    record,
+                    LogRecord {
+                        contract_id: Some(contract_id.clone()),
+                        level: Level::Debug,
+                        msg: msg.into(),
+                        args,
+                        fields: std::vec![],
+                    }
+                );
             }
         } else {
-            assert_eq!(env.logger().all(), std::vec![""; 0]);
+            assert_eq!(env.logger().all(), std::vec![]);
+        }
+    }
+
+    #[test]
+    fn test_logging_data() {
+        use soroban_sdk::xdr::{ContractEventBody, ContractEventType};
+
+        let env = Env::default();
+        let contract_id = env.register_contract(None, Contract);
+        let client = ContractClient::new(&env, &contract_id);
+
+        client.hello();
+
+        if cfg!(debug_assertions) {
+            // Logs without a level or fields are the message followed by the
+            // args, without a level, fields, or nesting of the args.
+            let data: std::vec::Vec<_> = env
+                .host()
+                .get_events()
+                .unwrap()
+                .0
+                .into_iter()
+                .filter_map(|e| match (&e.event.type_, &e.event.body) {
+                    (ContractEventType::Diagnostic, ContractEventBody::V0(ce))
+                        if ce.topics.as_slice() == [sym("log")] =>
+                    {
+                        Some(ce.data.clone())
+                    }
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(data.len(), 6);
+            let ScVal::Vec(Some(vals)) = &data[4] else {
+                panic!("log data is not a vec: {:?}", data[4]);
+            };
+            assert_eq!(&vals[1..], [sym("one"), sym("two")]);
+        }
+    }
+
+    #[test]
+    fn test_logging_levels_and_fields() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, Contract);
+        let client = ContractClient::new(&env, &contract_id);
+
+        client.levels();
+
+        if cfg!(debug_assertions) {
+            let all = env.logger().all();
+            let summary: std::vec::Vec<_> = all
+                .iter()
+                .map(|r| (r.level, r.msg.as_str(), r.args.len(), r.fields.len()))
+                .collect();
+            assert_eq!(
+                summary,
+                [
+                    (Level::Error, "error", 0, 0),
+                    (Level::Warn, "warn", 1, 0),
+                    (Level::Info, "info", 1, 2),
+                    (Level::Trace, "trace", 0, 1),
+                    (Level::Debug, "debug", 0, 1),
+                ]
+            );
+            assert_eq!(all[1].args, [ScVal::U32(1)]);
+            // Fields are ordered by name.
+            assert_eq!(
+                all[2].fields,
+                [("a".into(), sym("a")), ("b".into(), ScVal::U32(2))]
+            );
+        } else {
+            assert_eq!(env.logger().all(), std::vec![]);
+        }
+    }
+
+    #[test]
+    fn test_logging_message() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, Contract);
+        let client = ContractClient::new(&env, &contract_id);
+
+        let to = Address::random(&env);
+        client.render(&to);
+
+        env.logger().print();
+
+        if cfg!(debug_assertions) {
+            let record = env.logger().all().pop().unwrap();
+            let to_strkey = to.to_strkey();
+            let contract_strkey = contract_id.to_strkey();
+            assert_eq!(
+                record.message(),
+                std::format!("sent 10 to {to_strkey} [a, b] memo=0xab01")
+            );
+            assert_eq!(
+                std::format!("{record}"),
+                std::format!("[info] {contract_strkey}: sent 10 to {to_strkey} [a, b] memo=0xab01")
+            );
+            // The record is listed beneath the invocation that logged it.
+            assert_eq!(
+                env.logger().lines(),
+                [
+                    std::format!("{contract_strkey}::render"),
+                    std::format!("  [info] sent 10 to {to_strkey} [a, b] memo=0xab01"),
+                ]
+            );
         }
     }
 }